@@ -0,0 +1,105 @@
+// Compares the solver with the permutation search it replaced, run with `cargo bench`.
+//
+// 3 letters    columns      2.98µs/solve
+// 3 letters    brute force  85.683µs/solve
+// 8 letters    columns      387.432µs/solve
+// 8 letters    brute force  318.420962ms/solve
+// 10 letters   columns      199.869µs/solve
+// 10 letters   brute force  442.335853ms/solve
+// 199 addends  columns      4.599829ms/solve
+// 199 addends  brute force  5.260815653s/solve
+
+use std::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+
+use alphametics::solve;
+use itertools::Itertools;
+
+// The permutation search as it was, trying every assignment of digits and reparsing the sum
+mod brute_force {
+    use super::*;
+
+    fn eval(expr: &str) -> i64 {
+        expr.split('+')
+            .map(|s| s.trim().parse::<i64>().unwrap())
+            .sum()
+    }
+
+    pub fn solve(input: &str) -> Option<HashMap<char, u8>> {
+        let chars = input
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .collect::<HashSet<char>>();
+
+        let parts = input
+            .split_terminator("==")
+            .map(|s| s.trim())
+            .collect::<Vec<&str>>();
+
+        let (left_eq, right_eq) = (parts[0], parts[1]);
+
+        let left_parts: Vec<&str> = left_eq.split_terminator('+').map(|s| s.trim()).collect();
+        let leading_chars = left_parts
+            .iter()
+            .chain(Some(&right_eq))
+            .map(|w| w.chars().next().unwrap())
+            .collect::<Vec<char>>();
+
+        for perm in "0123456789".chars().permutations(chars.len()) {
+            if leading_chars
+                .iter()
+                .any(|&ch| perm[chars.iter().position(|&c| c == ch).unwrap()] == '0')
+            {
+                continue;
+            }
+
+            let trans: HashMap<char, char> =
+                chars.iter().cloned().zip(perm.iter().cloned()).collect();
+            let translate = |eq: &str| -> String {
+                eq.chars()
+                    .map(|c| trans.get(&c).copied().unwrap_or(c))
+                    .collect()
+            };
+
+            if eval(&translate(left_eq)) == translate(right_eq).parse::<i64>().unwrap() {
+                return Some(
+                    trans
+                        .into_iter()
+                        .map(|(x, c)| (x, c.to_digit(10).unwrap() as u8))
+                        .collect(),
+                );
+            }
+        }
+
+        None
+    }
+}
+
+const PUZZLES: [(&str, &str); 4] = [
+    ("3 letters", "I + BB == ILL"),
+    ("8 letters", "SEND + MORE == MONEY"),
+    (
+        "10 letters",
+        "AND + A + STRONG + OFFENSE + AS + A + GOOD == DEFENSE",
+    ),
+    ("199 addends", "THIS + A + FIRE + THEREFORE + FOR + ALL + HISTORIES + I + TELL + A + TALE + THAT + FALSIFIES + ITS + TITLE + TIS + A + LIE + THE + TALE + OF + THE + LAST + FIRE + HORSES + LATE + AFTER + THE + FIRST + FATHERS + FORESEE + THE + HORRORS + THE + LAST + FREE + TROLL + TERRIFIES + THE + HORSES + OF + FIRE + THE + TROLL + RESTS + AT + THE + HOLE + OF + LOSSES + IT + IS + THERE + THAT + SHE + STORES + ROLES + OF + LEATHERS + AFTER + SHE + SATISFIES + HER + HATE + OFF + THOSE + FEARS + A + TASTE + RISES + AS + SHE + HEARS + THE + LEAST + FAR + HORSE + THOSE + FAST + HORSES + THAT + FIRST + HEAR + THE + TROLL + FLEE + OFF + TO + THE + FOREST + THE + HORSES + THAT + ALERTS + RAISE + THE + STARES + OF + THE + OTHERS + AS + THE + TROLL + ASSAILS + AT + THE + TOTAL + SHIFT + HER + TEETH + TEAR + HOOF + OFF + TORSO + AS + THE + LAST + HORSE + FORFEITS + ITS + LIFE + THE + FIRST + FATHERS + HEAR + OF + THE + HORRORS + THEIR + FEARS + THAT + THE + FIRES + FOR + THEIR + FEASTS + ARREST + AS + THE + FIRST + FATHERS + RESETTLE + THE + LAST + OF + THE + FIRE + HORSES + THE + LAST + TROLL + HARASSES + THE + FOREST + HEART + FREE + AT + LAST + OF + THE + LAST + TROLL + ALL + OFFER + THEIR + FIRE + HEAT + TO + THE + ASSISTERS + FAR + OFF + THE + TROLL + FASTS + ITS + LIFE + SHORTER + AS + STARS + RISE + THE + HORSES + REST + SAFE + AFTER + ALL + SHARE + HOT + FISH + AS + THEIR + AFFILIATES + TAILOR + A + ROOFS + FOR + THEIR + SAFE == FORTRESSES"),
+];
+
+fn bench(name: &str, solver: &str, f: impl Fn() -> Option<HashMap<char, u8>>) {
+    let start = Instant::now();
+    let mut runs = 0;
+    while runs == 0 || start.elapsed().as_millis() < 200 {
+        assert!(f().is_some());
+        runs += 1;
+    }
+    println!("{name:<12} {solver:<12} {:?}/solve", start.elapsed() / runs);
+}
+
+fn main() {
+    for (name, puzzle) in PUZZLES {
+        bench(name, "columns", || solve(puzzle));
+        bench(name, "brute force", || brute_force::solve(puzzle));
+    }
+}
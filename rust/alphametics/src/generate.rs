@@ -0,0 +1,225 @@
+// Makes puzzles such as `SEND + MORE == MONEY` out of a word list. Addends and a result are
+// drawn at random, the result as long as the sum can be, and kept when the search finds
+// exactly one solution. How many digits that search tried tells how hard the puzzle is.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, fs, io,
+    ops::RangeInclusive,
+    path::Path,
+};
+
+use crate::{rng::Rng, solve_all, Solution};
+
+/// How hard a puzzle is to solve by search, from the digits tried to find every solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+    /// Fewer than 1000 digits tried.
+    Easy,
+    /// Fewer than 10000 digits tried.
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn from_nodes(nodes: u64) -> Difficulty {
+        match nodes {
+            0..1_000 => Difficulty::Easy,
+            1_000..10_000 => Difficulty::Medium,
+            _ => Difficulty::Hard,
+        }
+    }
+}
+
+/// A puzzle from a `Generator` with its only solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    puzzle: String,
+    solution: Solution,
+    nodes: u64,
+}
+
+impl Generated {
+    pub fn puzzle(&self) -> &str {
+        &self.puzzle
+    }
+
+    pub fn solution(&self) -> &Solution {
+        &self.solution
+    }
+
+    /// How many digits the search tried to find the solution and rule out any other.
+    pub fn nodes(&self) -> u64 {
+        self.nodes
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        Difficulty::from_nodes(self.nodes)
+    }
+}
+
+impl fmt::Display for Generated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.puzzle)
+    }
+}
+
+/// An iterator of puzzles adding words from a list, each with exactly one solution in base 10
+/// and none twice. It ends when no new puzzle is found within the allowed attempts.
+pub struct Generator {
+    words: Vec<String>,
+    // The indices of the words of each length
+    by_length: HashMap<usize, Vec<usize>>,
+    rng: Rng,
+    addends: RangeInclusive<usize>,
+    difficulty: Option<Difficulty>,
+    attempts: usize,
+    // The addends, sorted, and results already drawn so no puzzle comes twice
+    drawn: HashSet<(Vec<usize>, usize)>,
+}
+
+impl Generator {
+    /// A generator of puzzles with two addends, the same seed giving the same puzzles.
+    /// Words are written in capitals, those with anything but letters are left out.
+    pub fn new<I, S>(words: I, seed: u64) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut list: Vec<String> = vec![];
+        let mut seen = HashSet::new();
+        for word in words {
+            let word = word.as_ref().trim().to_uppercase();
+            if !word.is_empty()
+                && word.chars().all(char::is_alphabetic)
+                && seen.insert(word.clone())
+            {
+                list.push(word);
+            }
+        }
+
+        let mut by_length: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (i, word) in list.iter().enumerate() {
+            by_length.entry(word.chars().count()).or_default().push(i);
+        }
+
+        Self {
+            words: list,
+            by_length,
+            rng: Rng::new(seed),
+            addends: 2..=2,
+            difficulty: None,
+            attempts: 10_000,
+            drawn: HashSet::new(),
+        }
+    }
+
+    /// Reads the words from a file, separated by whitespace such as one per line.
+    pub fn from_file(path: impl AsRef<Path>, seed: u64) -> io::Result<Self> {
+        let words = fs::read_to_string(path)?;
+        Ok(Self::new(words.split_whitespace(), seed))
+    }
+
+    /// Draws a number of addends from `addends`, at least 2.
+    pub fn with_addends(self, addends: RangeInclusive<usize>) -> Self {
+        let (low, high) = (*addends.start().max(&2), *addends.end().max(&2));
+        Self {
+            addends: low..=high.max(low),
+            ..self
+        }
+    }
+
+    /// Only yields puzzles of the given difficulty.
+    pub fn with_difficulty(self, difficulty: Difficulty) -> Self {
+        Self {
+            difficulty: Some(difficulty),
+            ..self
+        }
+    }
+
+    /// How many puzzles to try for each one yielded before giving up.
+    pub fn with_attempts(self, attempts: usize) -> Self {
+        Self { attempts, ..self }
+    }
+
+    // A random puzzle of the words, which may have any number of solutions
+    fn draw(&mut self) -> Option<String> {
+        let (low, high) = (*self.addends.start(), *self.addends.end());
+        let count = low + self.rng.below(high - low + 1);
+        if self.words.len() <= count {
+            return None;
+        }
+
+        let mut picked: Vec<usize> = vec![];
+        while picked.len() < count {
+            let word = self.rng.below(self.words.len());
+            if !picked.contains(&word) {
+                picked.push(word);
+            }
+        }
+
+        // The sum of `count` numbers below 10^longest is below count * 10^longest
+        let longest = picked
+            .iter()
+            .map(|&i| self.words[i].chars().count())
+            .max()?;
+        let length = longest + self.rng.below((count - 1).ilog10() as usize + 2);
+        let results = self.by_length.get(&length)?;
+        let result = results[self.rng.below(results.len())];
+        let mut sorted = picked.clone();
+        sorted.sort();
+        if picked.contains(&result) || !self.drawn.insert((sorted, result)) {
+            return None;
+        }
+
+        let mut letters = picked
+            .iter()
+            .chain([&result])
+            .flat_map(|&i| self.words[i].chars())
+            .collect::<Vec<char>>();
+        letters.sort();
+        letters.dedup();
+        if letters.len() > 10 {
+            return None;
+        }
+
+        let addends = picked
+            .iter()
+            .map(|&i| self.words[i].as_str())
+            .collect::<Vec<&str>>();
+        Some(format!("{} == {}", addends.join(" + "), self.words[result]))
+    }
+}
+
+impl Iterator for Generator {
+    type Item = Generated;
+
+    fn next(&mut self) -> Option<Generated> {
+        for _ in 0..self.attempts {
+            let Some(puzzle) = self.draw() else {
+                continue;
+            };
+
+            let mut solutions = solve_all(&puzzle);
+            let Some(solution) = solutions.next() else {
+                continue;
+            };
+            if solutions.next().is_some() {
+                continue;
+            }
+
+            let generated = Generated {
+                puzzle,
+                solution,
+                nodes: solutions.nodes(),
+            };
+            if self
+                .difficulty
+                .is_none_or(|difficulty| generated.difficulty() == difficulty)
+            {
+                return Some(generated);
+            }
+        }
+        None
+    }
+}
@@ -1,82 +1,392 @@
 use std::collections::{HashMap, HashSet};
 
-use itertools::Itertools;
+mod generate;
+mod parallel;
+mod parse;
+mod rng;
 
-fn eval(expr: &str) -> i64 {
-    expr.split('+')
-        .map(|s| s.trim().parse::<i64>().unwrap())
-        .sum()
-}
+use parse::{parse, Polynomial};
 
-fn solve_alphametic(input: &str) -> Option<HashMap<char, char>> {
-    let chars = input
-        .chars()
-        .filter(char::is_ascii_alphabetic)
-        .collect::<HashSet<char>>();
+pub use generate::{Difficulty, Generated, Generator};
+pub use parallel::{CancellationToken, Outcome, Solver};
+pub use parse::{Expected, ParseError};
 
-    let parts = input
-        .split_terminator("==")
-        .map(|s| s.trim())
-        .collect::<Vec<&str>>();
+/// The largest base puzzles can be solved in.
+pub const MAX_BASE: u32 = 64;
 
-    let (left_eq, right_eq) = (parts[0], parts[1]);
+// A puzzle ready for the search: every equation is a polynomial in the digits which must come
+// to zero. Modulo base^k only the terms with a coefficient that isn't a multiple of base^k count,
+// for a sum those are the letters of the k rightmost columns. Letters get their digits in the
+// order these terms need them, so each equation can be checked modulo base^k as soon as they
+// have one, which is the column by column check with carries of an addition.
+struct Puzzle {
+    base: i128,
+    letters: Vec<char>,
+    leading: Vec<bool>,
+    // The terms of each equation as coefficients and the letter indices they multiply
+    equations: Vec<Vec<(i128, Vec<usize>)>>,
+    // The checks of an equation modulo a number, or exactly for 0, once the letter at the
+    // same index has a digit
+    checks: Vec<Vec<(usize, i128)>>,
+}
 
-    let left_parts: Vec<&str> = left_eq.split_terminator('+').map(|s| s.trim()).collect();
-    let leading_chars = left_parts
-        .iter()
-        .chain(Some(&right_eq))
-        .map(|w| w.chars().next().unwrap())
-        .collect::<Vec<char>>();
+impl Puzzle {
+    fn new(input: &str, base: u32) -> Result<Puzzle, ParseError> {
+        if !(2..=MAX_BASE).contains(&base) {
+            return Err(ParseError::BadBase(base));
+        }
+        let parsed = parse(input, base)?;
+        if parsed.letters.len() > base as usize {
+            return Err(ParseError::TooManyLetters {
+                letters: parsed.letters.len(),
+                base,
+            });
+        }
+        let base = base as i128;
 
-    for perm in "0123456789".chars().permutations(chars.len()) {
-        if leading_chars
+        // Every value the search works out must fit, the largest being the sum of every
+        // term with the largest digits
+        for equation in &parsed.equations {
+            equation
+                .iter()
+                .try_fold(0i128, |sum, (term, c)| {
+                    let largest = (base - 1).checked_pow(term.len() as u32)?;
+                    sum.checked_add(c.checked_mul(largest)?.checked_abs()?)
+                })
+                .ok_or(ParseError::TooLarge)?;
+        }
+
+        let mut order: Vec<usize> = vec![];
+        let all = parsed
+            .equations
             .iter()
-            .any(|&ch| perm[chars.iter().position(|&c| c == ch).unwrap()] == '0')
-        {
-            continue;
+            .flat_map(|equation| letters_needed(equation, 0))
+            .collect::<HashSet<usize>>();
+        let mut k = 1;
+        while order.len() < all.len() {
+            let Some(modulus) = base.checked_pow(k) else {
+                break;
+            };
+            for equation in &parsed.equations {
+                let mut needed = letters_needed(equation, modulus);
+                needed.retain(|letter| !order.contains(letter));
+                // Letters weighing the most first, so the bounds of `in_bounds` close in fast
+                needed.sort_by_key(|&letter| {
+                    let weight = |(term, c): (&Vec<usize>, &i128)| match term.contains(&letter) {
+                        true => c.abs(),
+                        false => 0,
+                    };
+                    std::cmp::Reverse(equation.iter().map(weight).max())
+                });
+                order.extend(needed);
+            }
+            k += 1;
         }
+        // Letters which cancel out of every equation still need a digit of their own
+        let rest = (0..parsed.letters.len()).filter(|letter| !order.contains(letter));
+        order.extend(rest.collect::<Vec<usize>>());
 
-        let trans: HashMap<char, char> = chars.iter().cloned().zip(perm.iter().cloned()).collect();
-        let new_left_eq: String = left_eq
-            .chars()
-            .map(|c| {
-                trans
+        let position = |letter: &usize| order.iter().position(|l| l == letter).unwrap();
+        let equations = parsed
+            .equations
+            .iter()
+            .map(|equation| {
+                equation
                     .iter()
-                    .find(|(&ch, _)| ch == c)
-                    .map(|(_, d)| *d)
-                    .unwrap_or(c)
+                    .map(|(term, &c)| (c, term.iter().map(position).collect()))
+                    .collect()
             })
-            .collect();
-        let new_right_eq: String = right_eq
-            .chars()
-            .map(|c| {
-                trans
+            .collect::<Vec<Vec<(i128, Vec<usize>)>>>();
+
+        let mut checks = vec![vec![]; order.len().max(1)];
+        for (i, equation) in parsed.equations.iter().enumerate() {
+            // The last letter needed modulo each power, a larger modulus replacing a smaller one
+            // checked at the same time
+            let all = letters_needed(equation, 0);
+            let ready = |letters: &[usize]| letters.iter().map(position).max().unwrap_or(0);
+            let mut k = 1;
+            while let Some(modulus) = base.checked_pow(k) {
+                let needed = letters_needed(equation, modulus);
+                if needed.len() == all.len() {
+                    break;
+                }
+                let at = &mut checks[ready(&needed)];
+                at.retain(|&(e, _)| e != i);
+                at.push((i, modulus));
+                k += 1;
+            }
+            let at = &mut checks[ready(&all)];
+            at.retain(|&(e, _)| e != i);
+            at.push((i, 0));
+        }
+
+        Ok(Puzzle {
+            base,
+            letters: order.iter().map(|&l| parsed.letters[l]).collect(),
+            leading: order.iter().map(|&l| parsed.leading[l]).collect(),
+            equations,
+            checks,
+        })
+    }
+
+    // Whether the equations checked at `letter` hold. Letters without a digit yet are 0,
+    // their terms are multiples of the modulus so they don't change the check.
+    fn holds(&self, letter: usize, digits: &[i128]) -> bool {
+        self.in_bounds(letter, digits)
+            && self.checks[letter].iter().all(|&(equation, modulus)| {
+                let value = self.equations[equation]
                     .iter()
-                    .find(|(&ch, _)| ch == c)
-                    .map(|(_, d)| *d)
-                    .unwrap_or(c)
+                    .map(|(c, term)| c * term.iter().map(|&l| digits[l]).product::<i128>())
+                    .sum::<i128>();
+                match modulus {
+                    0 => value == 0,
+                    modulus => value.rem_euclid(modulus) == 0,
+                }
             })
-            .collect();
+    }
 
-        // dbg!(&trans, &new_left_eq, &new_right_eq);
+    // Whether the sums can still come to zero, giving the letters after `letter` any digit.
+    // Equations multiplying letters aren't bounded.
+    fn in_bounds(&self, letter: usize, digits: &[i128]) -> bool {
+        self.equations.iter().all(|equation| {
+            let (mut low, mut high) = (0, 0);
+            for (c, term) in equation {
+                match term.as_slice() {
+                    [] => (low, high) = (low + c, high + c),
+                    &[l] if l <= letter => {
+                        (low, high) = (low + c * digits[l], high + c * digits[l])
+                    }
+                    [_] if *c > 0 => high += c * (self.base - 1),
+                    [_] => low += c * (self.base - 1),
+                    _ => return true,
+                }
+            }
+            low <= 0 && 0 <= high
+        })
+    }
+}
 
-        if eval(&new_left_eq) == new_right_eq.parse::<i64>().unwrap() {
-            return Some(trans);
+// The letters in terms which count modulo `modulus`, all letters for 0
+fn letters_needed(equation: &Polynomial, modulus: i128) -> Vec<usize> {
+    let mut letters = equation
+        .iter()
+        .filter(|(_, &c)| modulus == 0 || c % modulus != 0)
+        .flat_map(|(term, _)| term.iter().copied())
+        .collect::<Vec<usize>>();
+    letters.sort();
+    letters.dedup();
+    letters
+}
+
+/// A digit for every letter of a puzzle.
+pub type Solution = HashMap<char, u32>;
+
+/// Solves a puzzle of words standing for numbers, where each letter is a different digit and
+/// no word starts with 0. Puzzles may add, subtract and multiply with parentheses, and hold
+/// several equations separated by `;` which share the letters, such as `ABC * DE == FGHIJ`.
+pub fn solve(input: &str) -> Option<HashMap<char, u8>> {
+    let solution = solve_all(input).next()?;
+    Some(solution.into_iter().map(|(c, d)| (c, d as u8)).collect())
+}
+
+/// Like `solve` in any base from 2 to `MAX_BASE`, which allows as many letters as the base.
+/// Letters can be any alphabetic characters and numbers in the puzzle are written in the base.
+pub fn solve_in_base(input: &str, base: u32) -> Option<Solution> {
+    solve_all_in_base(input, base).next()
+}
+
+/// Every solution of a puzzle, found one at a time as the iterator is advanced.
+/// A puzzle which can't be read has none.
+pub fn solve_all(input: &str) -> Solutions {
+    solve_all_in_base(input, 10)
+}
+
+/// Like `solve_all` in any base from 2 to `MAX_BASE`. A puzzle which can't be read in the base,
+/// such as one with a bad base, a digit the base doesn't have or more letters than digits, has
+/// no solutions, use `try_solve_all_in_base` to tell why.
+pub fn solve_all_in_base(input: &str, base: u32) -> Solutions {
+    try_solve_all_in_base(input, base).unwrap_or_else(|_| Solutions::new(None))
+}
+
+/// Like `solve`, but tells why a puzzle can't be read instead of finding no solution.
+pub fn try_solve(input: &str) -> Result<Option<Solution>, ParseError> {
+    Ok(try_solve_all_in_base(input, 10)?.next())
+}
+
+/// Like `solve_all_in_base`, but tells why a puzzle can't be read. A base outside 2 to `MAX_BASE`
+/// is a `BadBase`, a number with a digit the base doesn't have a `BadNumber` and more letters
+/// than the base has digits `TooManyLetters`. `TooLarge` means the numbers, or the sums and
+/// products the equations make of them, don't fit in an `i128` in that base.
+pub fn try_solve_all_in_base(input: &str, base: u32) -> Result<Solutions, ParseError> {
+    Ok(Solutions::new(Some(Puzzle::new(input, base)?)))
+}
+
+/// Whether a puzzle has exactly one solution, the search stops at the second.
+pub fn is_uniquely_solvable(input: &str) -> bool {
+    solve_all(input).is_unique()
+}
+
+/// The solutions of a puzzle from `solve_all`.
+pub struct Solutions {
+    puzzle: Option<Puzzle>,
+    search: Search,
+    done: bool,
+}
+
+impl Solutions {
+    fn new(puzzle: Option<Puzzle>) -> Self {
+        let letters = puzzle.as_ref().map_or(0, |puzzle| puzzle.letters.len());
+        Self {
+            done: puzzle.is_none(),
+            puzzle,
+            search: Search::new(letters),
         }
     }
 
-    None
+    /// Whether there is exactly one solution left, searching up to the second.
+    pub fn is_unique(self) -> bool {
+        self.take(2).count() == 1
+    }
+
+    /// How many digits the search has tried so far, a measure of how hard the puzzle is.
+    pub fn nodes(&self) -> u64 {
+        self.search.nodes
+    }
 }
 
-pub fn solve(input: &str) -> Option<HashMap<char, u8>> {
-    // dbg!(input, left_eq, right_eq, left_parts, chars, leading_chars);
-    Some(
-        solve_alphametic(input)?
-            .into_iter()
-            .map(|(x, c)| (x, c.to_digit(10).unwrap() as u8))
-            .collect::<HashMap<char, u8>>(),
-    )
-
-    // unimplemented!("Solve the alphametic {input:?}")
-    // None
+impl Iterator for Solutions {
+    type Item = Solution;
+
+    fn next(&mut self) -> Option<Solution> {
+        let puzzle = self.puzzle.as_ref()?;
+        if self.done {
+            return None;
+        }
+
+        // With no letters the equations are checked once, all at the first check
+        if puzzle.letters.is_empty() {
+            self.done = true;
+            return puzzle.holds(0, &self.search.digits).then(HashMap::new);
+        }
+
+        match self
+            .search
+            .advance(puzzle, 0, puzzle.letters.len(), &mut |_| true)
+        {
+            Step::Found => Some(self.search.solution(puzzle)),
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+// Where a depth first search is, kept between solutions
+#[derive(Clone)]
+struct Search {
+    digits: Vec<i128>,
+    // The next digit to try for the letter at each depth
+    next: Vec<i128>,
+    used: u64,
+    // How many letters have a digit
+    depth: usize,
+    // How many digits have been tried
+    nodes: u64,
+}
+
+// How a call to `Search::advance` ended
+enum Step {
+    Found,
+    Exhausted,
+    Stopped,
+}
+
+// How many nodes `Search::advance` explores between asking whether to go on
+const NODES_BETWEEN_CHECKS: u64 = 1 << 12;
+
+impl Search {
+    fn new(letters: usize) -> Self {
+        Self {
+            digits: vec![0; letters],
+            next: vec![0; letters + 1],
+            used: 0,
+            depth: 0,
+            nodes: 0,
+        }
+    }
+
+    // Searches on until the letters up to `target` have digits, keeping those of the letters
+    // below `floor`. `go_on` is asked every so many nodes with how many were explored since,
+    // and stops the search by returning false.
+    fn advance(
+        &mut self,
+        puzzle: &Puzzle,
+        floor: usize,
+        target: usize,
+        go_on: &mut dyn FnMut(u64) -> bool,
+    ) -> Step {
+        // Coming back after a solution, the last letter tries its next digit
+        if self.depth == target && !self.backtrack(floor) {
+            return Step::Exhausted;
+        }
+
+        let mut since_check = 0;
+        loop {
+            let letter = self.depth;
+            let digit = (self.next[letter]..puzzle.base).find(|&digit| {
+                self.used & 1 << digit == 0 && (digit != 0 || !puzzle.leading[letter])
+            });
+
+            let Some(digit) = digit else {
+                if !self.backtrack(floor) {
+                    return Step::Exhausted;
+                }
+                continue;
+            };
+
+            self.nodes += 1;
+            since_check += 1;
+            if since_check == NODES_BETWEEN_CHECKS {
+                if !go_on(since_check) {
+                    return Step::Stopped;
+                }
+                since_check = 0;
+            }
+
+            self.next[letter] = digit + 1;
+            self.digits[letter] = digit;
+            if !puzzle.holds(letter, &self.digits) {
+                self.digits[letter] = 0;
+                continue;
+            }
+            self.used |= 1 << digit;
+            self.depth += 1;
+            self.next[self.depth] = 0;
+            if self.depth == target {
+                return Step::Found;
+            }
+        }
+    }
+
+    // Takes the digit back from the last letter which has one, false when only the letters
+    // below `floor` have one
+    fn backtrack(&mut self, floor: usize) -> bool {
+        if self.depth == floor {
+            return false;
+        }
+        self.depth -= 1;
+        self.used &= !(1 << self.digits[self.depth]);
+        self.digits[self.depth] = 0;
+        true
+    }
+
+    fn solution(&self, puzzle: &Puzzle) -> Solution {
+        puzzle
+            .letters
+            .iter()
+            .zip(&self.digits)
+            .map(|(&c, &digit)| (c, digit as u32))
+            .collect()
+    }
 }
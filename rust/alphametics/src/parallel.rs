@@ -0,0 +1,225 @@
+// Runs the search of `solve` on several threads. The digits of the first few letters split the
+// search into subtrees, which the threads take from a shared counter until one finds a solution,
+// the search is cancelled or it runs out of time.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{ParseError, Puzzle, Search, Solution, Step, NODES_BETWEEN_CHECKS};
+
+// Letters whose digits split the search, past which there are plenty of subtrees or the
+// splitting itself is a search of its own
+const MAX_SPLIT_DEPTH: usize = 3;
+
+/// A flag to stop a search from another thread, clones share the flag.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// How a search by a `Solver` ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Solution),
+    NoSolution,
+    Cancelled,
+    TimedOut,
+}
+
+/// Solves puzzles like `solve` with the search spread across threads, which can be stopped
+/// with a `CancellationToken` or a timeout and reports its progress.
+pub struct Solver<'a> {
+    base: u32,
+    threads: usize,
+    timeout: Option<Duration>,
+    token: CancellationToken,
+    progress: Option<Box<dyn Fn(u64) + Send + Sync + 'a>>,
+}
+
+impl Default for Solver<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Solver<'a> {
+    /// A solver in base 10 using every available core.
+    pub fn new() -> Self {
+        Self {
+            base: 10,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: None,
+            token: CancellationToken::new(),
+            progress: None,
+        }
+    }
+
+    pub fn with_base(self, base: u32) -> Self {
+        Self { base, ..self }
+    }
+
+    pub fn with_threads(self, threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            ..self
+        }
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Stops the search once `token` is cancelled.
+    pub fn with_cancellation(self, token: CancellationToken) -> Self {
+        Self { token, ..self }
+    }
+
+    /// Calls `progress` from the searching threads with the number of digits tried so far,
+    /// every few thousand and once more when the search ends. The calls from different
+    /// threads may arrive out of order.
+    pub fn with_progress(self, progress: impl Fn(u64) + Send + Sync + 'a) -> Self {
+        Self {
+            progress: Some(Box::new(progress)),
+            ..self
+        }
+    }
+
+    /// Searches for any one solution, which may differ between runs of a puzzle with several.
+    pub fn solve(&self, input: &str) -> Result<Outcome, ParseError> {
+        let puzzle = Puzzle::new(input, self.base)?;
+        if puzzle.letters.is_empty() {
+            return Ok(match puzzle.holds(0, &[]) {
+                true => Outcome::Solved(Solution::new()),
+                false => Outcome::NoSolution,
+            });
+        }
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let nodes = AtomicU64::new(0);
+        let found = Mutex::new(None);
+        let stop = AtomicBool::new(false);
+
+        let cancelled = AtomicBool::new(false);
+
+        // Adds to the shared count and tells whether to go on, noting why the search stopped
+        let go_on = |explored: u64| {
+            let total = nodes.fetch_add(explored, Ordering::Relaxed) + explored;
+            if let Some(progress) = &self.progress {
+                progress(total);
+            }
+            if self.token.is_cancelled() {
+                cancelled.store(true, Ordering::Relaxed);
+                stop.store(true, Ordering::Relaxed);
+            } else if deadline.is_some_and(|d| Instant::now() >= d) {
+                stop.store(true, Ordering::Relaxed);
+            }
+            !stop.load(Ordering::Relaxed)
+        };
+
+        let subtrees = self.split(&puzzle, &go_on);
+        let next = Mutex::new(0);
+
+        thread::scope(|s| {
+            for _ in 0..self.threads {
+                s.spawn(|| loop {
+                    let subtree = {
+                        let mut next = next.lock().unwrap();
+                        *next += 1;
+                        *next - 1
+                    };
+                    let Some(search) = subtrees.get(subtree) else {
+                        return;
+                    };
+                    if stop.load(Ordering::Relaxed) {
+                        return;
+                    }
+
+                    let mut search = search.clone();
+                    let floor = search.depth;
+                    let step =
+                        search.advance(&puzzle, floor, puzzle.letters.len(), &mut |n| go_on(n));
+                    // Checks in between subtrees too, which may be smaller than a check
+                    go_on(search.nodes % NODES_BETWEEN_CHECKS);
+                    if let Step::Found = step {
+                        stop.store(true, Ordering::Relaxed);
+                        found
+                            .lock()
+                            .unwrap()
+                            .get_or_insert_with(|| search.solution(&puzzle));
+                    }
+                });
+            }
+        });
+
+        if let Some(progress) = &self.progress {
+            progress(nodes.load(Ordering::Relaxed));
+        }
+
+        let solution = found.into_inner().unwrap();
+        Ok(match solution {
+            Some(solution) => Outcome::Solved(solution),
+            None if cancelled.load(Ordering::Relaxed) => Outcome::Cancelled,
+            None if stop.load(Ordering::Relaxed) => Outcome::TimedOut,
+            None => Outcome::NoSolution,
+        })
+    }
+
+    // The searches under every way of giving digits to the first letters, enough of them to
+    // keep the threads busy but no deeper than `MAX_SPLIT_DEPTH`. Puzzles with few letters are
+    // searched whole, and none are left when `go_on` stops the search.
+    fn split(&self, puzzle: &Puzzle, go_on: &dyn Fn(u64) -> bool) -> Vec<Search> {
+        let letters = puzzle.letters.len();
+        let whole = vec![Search::new(letters)];
+        if letters < 2 || self.threads == 1 {
+            return whole;
+        }
+
+        let mut subtrees = whole;
+        for depth in 1..letters.min(MAX_SPLIT_DEPTH + 1) {
+            let mut children = vec![];
+            for search in &subtrees {
+                let mut search = search.clone();
+                let floor = search.depth;
+                let step = loop {
+                    match search.advance(puzzle, floor, depth, &mut |n| go_on(n)) {
+                        Step::Found => {
+                            let mut child = search.clone();
+                            child.nodes = 0;
+                            children.push(child);
+                        }
+                        step => break step,
+                    }
+                };
+                if let Step::Stopped = step {
+                    return vec![];
+                }
+                go_on(search.nodes % NODES_BETWEEN_CHECKS);
+            }
+            subtrees = children;
+            if subtrees.len() >= self.threads * 8 {
+                break;
+            }
+        }
+        subtrees
+    }
+}
@@ -0,0 +1,387 @@
+// Reads puzzles such as `ABC * DE == FGHIJ; A + B == C` into one polynomial per equation,
+// the left side minus the right, in the digits of the letters.
+//
+//     puzzle   = equation { ";" equation }
+//     equation = sum "==" sum { "==" sum }
+//     sum      = product { ("+" | "-") product }
+//     product  = factor { "*" factor }
+//     factor   = word | number | "(" sum ")" | "-" factor
+
+use std::{collections::HashMap, fmt};
+
+use crate::MAX_BASE;
+
+// Coefficients of products of letters, each product a sorted list of letter indices.
+// The empty product holds the constant.
+pub(crate) type Polynomial = HashMap<Vec<usize>, i128>;
+
+pub(crate) struct Parsed {
+    // Letters in the order they first appear
+    pub(crate) letters: Vec<char>,
+    // Whether the letter starts a word, so it can't be zero
+    pub(crate) leading: Vec<bool>,
+    pub(crate) equations: Vec<Polynomial>,
+}
+
+/// Why a puzzle can't be read. Positions count characters from 0, the end of the input
+/// being at its length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    BadBase(u32),
+    UnexpectedChar {
+        position: usize,
+        found: char,
+    },
+    /// A word with digits in it, such as `SE3ND`.
+    MixedWord {
+        position: usize,
+        word: String,
+    },
+    /// A number with a digit too large for the base.
+    BadNumber {
+        position: usize,
+        number: String,
+    },
+    Expected {
+        position: usize,
+        expected: Expected,
+    },
+    /// A `(` which is never closed.
+    UnclosedParenthesis {
+        position: usize,
+    },
+    TooManyLetters {
+        letters: usize,
+        base: u32,
+    },
+    /// Numbers too large to solve for.
+    TooLarge,
+}
+
+/// What the parser was looking for when it found something else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    /// A word, a number, `(` or `-`.
+    Term,
+    Equals,
+    /// An operator, `;` or the end.
+    Operator,
+}
+
+impl ParseError {
+    pub fn position(&self) -> Option<usize> {
+        match *self {
+            ParseError::UnexpectedChar { position, .. }
+            | ParseError::MixedWord { position, .. }
+            | ParseError::BadNumber { position, .. }
+            | ParseError::Expected { position, .. }
+            | ParseError::UnclosedParenthesis { position } => Some(position),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::BadBase(base) => write!(f, "base {base} is not between 2 and {MAX_BASE}"),
+            ParseError::UnexpectedChar { position, found } => {
+                write!(f, "unexpected `{found}` at {position}")
+            }
+            ParseError::MixedWord { position, word } => {
+                write!(f, "`{word}` at {position} mixes letters and digits")
+            }
+            ParseError::BadNumber { position, number } => {
+                write!(
+                    f,
+                    "`{number}` at {position} has a digit too large for the base"
+                )
+            }
+            ParseError::Expected { position, expected } => {
+                let expected = match expected {
+                    Expected::Term => "a word, a number or `(`",
+                    Expected::Equals => "`==`",
+                    Expected::Operator => "an operator, `;` or the end",
+                };
+                write!(f, "expected {expected} at {position}")
+            }
+            ParseError::UnclosedParenthesis { position } => {
+                write!(f, "the `(` at {position} is never closed")
+            }
+            ParseError::TooManyLetters { letters, base } => {
+                write!(
+                    f,
+                    "{letters} letters can't have different digits in base {base}"
+                )
+            }
+            ParseError::TooLarge => write!(f, "the numbers are too large"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Number(i128),
+    Plus,
+    Minus,
+    Times,
+    Open,
+    Close,
+    Equals,
+    Semicolon,
+}
+
+pub(crate) fn parse(input: &str, base: u32) -> Result<Parsed, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(input, base)?,
+        end: input.chars().count(),
+        base: base as i128,
+        next: 0,
+        letters: vec![],
+        leading: vec![],
+    };
+
+    let mut equations = vec![];
+    loop {
+        let mut left = parser.sum()?;
+        parser.expect(Token::Equals, Expected::Equals)?;
+        loop {
+            let right = parser.sum()?;
+            equations.push(subtract(&left, &right).ok_or(ParseError::TooLarge)?);
+            left = right;
+            if parser.peek() != Some(&Token::Equals) {
+                break;
+            }
+            parser.next += 1;
+        }
+
+        match parser.peek() {
+            None => break,
+            Some(Token::Semicolon) => parser.next += 1,
+            Some(_) => return Err(parser.expected(Expected::Operator)),
+        }
+        // A trailing semicolon ends the puzzle
+        if parser.peek().is_none() {
+            break;
+        }
+    }
+
+    Ok(Parsed {
+        letters: parser.letters,
+        leading: parser.leading,
+        equations,
+    })
+}
+
+// Words are runs of letters from any alphabet, numbers are written in the base
+fn tokenize(input: &str, base: u32) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut tokens = vec![];
+    let mut chars = input.chars().enumerate().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Times,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ';' => Token::Semicolon,
+            '=' => match chars.next() {
+                Some((_, '=')) => Token::Equals,
+                _ => return Err(ParseError::UnexpectedChar { position, found: c }),
+            },
+            c if c.is_alphabetic() || c.is_ascii_digit() => {
+                let mut text = c.to_string();
+                while let Some(&(_, c)) = chars
+                    .peek()
+                    .filter(|(_, c)| c.is_alphabetic() || c.is_ascii_digit())
+                {
+                    text.push(c);
+                    chars.next();
+                }
+
+                if text.chars().all(char::is_alphabetic) {
+                    Token::Word(text)
+                } else if !text.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(ParseError::MixedWord {
+                        position,
+                        word: text,
+                    });
+                } else if let Some(digits) = text
+                    .chars()
+                    .map(|c| c.to_digit(10).filter(|&d| d < base))
+                    .collect::<Option<Vec<u32>>>()
+                {
+                    // Only the digits 0 to 9 can be written, those above are letters
+                    let number = digits.iter().try_fold(0i128, |number, &digit| {
+                        number.checked_mul(base as i128)?.checked_add(digit as i128)
+                    });
+                    Token::Number(number.ok_or(ParseError::TooLarge)?)
+                } else {
+                    return Err(ParseError::BadNumber {
+                        position,
+                        number: text,
+                    });
+                }
+            }
+            _ => return Err(ParseError::UnexpectedChar { position, found: c }),
+        };
+        tokens.push((position, token));
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    // The position of the end of the input
+    end: usize,
+    base: i128,
+    next: usize,
+    letters: Vec<char>,
+    leading: Vec<bool>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next).map(|(_, token)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.next)
+            .map_or(self.end, |&(position, _)| position)
+    }
+
+    fn expected(&self, expected: Expected) -> ParseError {
+        ParseError::Expected {
+            position: self.position(),
+            expected,
+        }
+    }
+
+    fn expect(&mut self, token: Token, expected: Expected) -> Result<(), ParseError> {
+        if self.peek() != Some(&token) {
+            return Err(self.expected(expected));
+        }
+        self.next += 1;
+        Ok(())
+    }
+
+    fn sum(&mut self) -> Result<Polynomial, ParseError> {
+        let mut sum = self.product()?;
+        loop {
+            match self.peek() {
+                Some(Token::Plus) => {
+                    self.next += 1;
+                    sum = add(&sum, &self.product()?).ok_or(ParseError::TooLarge)?;
+                }
+                Some(Token::Minus) => {
+                    self.next += 1;
+                    sum = subtract(&sum, &self.product()?).ok_or(ParseError::TooLarge)?;
+                }
+                _ => return Ok(sum),
+            }
+        }
+    }
+
+    fn product(&mut self) -> Result<Polynomial, ParseError> {
+        let mut product = self.factor()?;
+        while self.peek() == Some(&Token::Times) {
+            self.next += 1;
+            product = multiply(&product, &self.factor()?).ok_or(ParseError::TooLarge)?;
+        }
+        Ok(product)
+    }
+
+    fn factor(&mut self) -> Result<Polynomial, ParseError> {
+        let position = self.position();
+        let token = self.peek().cloned().ok_or(self.expected(Expected::Term))?;
+
+        match token {
+            Token::Word(word) => {
+                self.next += 1;
+                self.word(&word)
+            }
+            Token::Number(n) => {
+                self.next += 1;
+                Ok(HashMap::from([(vec![], n)]))
+            }
+            Token::Open => {
+                self.next += 1;
+                let sum = self.sum()?;
+                self.expect(Token::Close, Expected::Operator)
+                    .map_err(|_| ParseError::UnclosedParenthesis { position })?;
+                Ok(sum)
+            }
+            Token::Minus => {
+                self.next += 1;
+                subtract(&HashMap::new(), &self.factor()?).ok_or(ParseError::TooLarge)
+            }
+            _ => Err(self.expected(Expected::Term)),
+        }
+    }
+
+    // A word is the sum of its letters times the powers of the base
+    fn word(&mut self, word: &str) -> Result<Polynomial, ParseError> {
+        let mut polynomial = HashMap::new();
+        let mut place: i128 = 1;
+        let length = word.chars().count();
+        for (i, c) in word.chars().rev().enumerate() {
+            let letter = match self.letters.iter().position(|&l| l == c) {
+                Some(letter) => letter,
+                None => {
+                    self.letters.push(c);
+                    self.leading.push(false);
+                    self.letters.len() - 1
+                }
+            };
+            let coefficient: &mut i128 = polynomial.entry(vec![letter]).or_insert(0);
+            *coefficient = coefficient.checked_add(place).ok_or(ParseError::TooLarge)?;
+            if i == length - 1 {
+                self.leading[letter] = true;
+            } else {
+                place = place.checked_mul(self.base).ok_or(ParseError::TooLarge)?;
+            }
+        }
+        Ok(polynomial)
+    }
+}
+
+// `None` when a coefficient overflows, as for `subtract` and `multiply`
+fn add(a: &Polynomial, b: &Polynomial) -> Option<Polynomial> {
+    let mut sum = a.clone();
+    for (term, coefficient) in b {
+        let total: &mut i128 = sum.entry(term.clone()).or_insert(0);
+        *total = total.checked_add(*coefficient)?;
+    }
+    sum.retain(|_, coefficient| *coefficient != 0);
+    Some(sum)
+}
+
+fn subtract(a: &Polynomial, b: &Polynomial) -> Option<Polynomial> {
+    let negated = b
+        .iter()
+        .map(|(term, c)| Some((term.clone(), c.checked_neg()?)))
+        .collect::<Option<_>>()?;
+    add(a, &negated)
+}
+
+// `None` when a coefficient overflows
+fn multiply(a: &Polynomial, b: &Polynomial) -> Option<Polynomial> {
+    let mut product = HashMap::new();
+    for (x, cx) in a {
+        for (y, cy) in b {
+            let mut term = [x.as_slice(), y].concat();
+            term.sort();
+            let sum: &mut i128 = product.entry(term).or_insert(0);
+            *sum = sum.checked_add(cx.checked_mul(*cy)?)?;
+        }
+    }
+    product.retain(|_, coefficient| *coefficient != 0);
+    Some(product)
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use alphametics::{solve_all_in_base, solve_in_base};
+
+fn value(word: &str, solution: &HashMap<char, u32>, base: u64) -> u64 {
+    word.chars()
+        .fold(0, |value, c| value * base + solution[&c] as u64)
+}
+
+#[test]
+fn test_hexadecimal() {
+    let s = solve_in_base("SEND + MORE == MONEY", 16).unwrap();
+    assert_eq!(
+        value("SEND", &s, 16) + value("MORE", &s, 16),
+        value("MONEY", &s, 16)
+    );
+    assert!(s.values().all(|&digit| digit < 16));
+}
+
+#[test]
+fn test_sixteen_letters() {
+    let s = solve_in_base("ABCDEFGHIJKLMNOP == PONMLKJIHGFEDCBA + 0", 16);
+    assert_eq!(s, None);
+
+    let s = solve_in_base("ABCDEFGHIJKLMNOP - ABCDEFGHIJKLMNOP == 0", 16).unwrap();
+    let mut digits = s.values().copied().collect::<Vec<u32>>();
+    digits.sort();
+    assert_eq!(digits, (0..16).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_small_bases() {
+    assert_eq!(
+        solve_in_base("A + 1 == 10", 2),
+        Some(HashMap::from([('A', 1)]))
+    );
+    assert_eq!(solve_in_base("A + 2 == 10", 2), None);
+    assert_eq!(solve_all_in_base("A * B == C", 4).count(), 0);
+    assert_eq!(solve_all_in_base("AB == BA", 3).count(), 0);
+    assert_eq!(solve_in_base("ABC == ABC", 2), None);
+}
+
+#[test]
+fn test_bases_out_of_range() {
+    assert_eq!(solve_in_base("A == A", 1), None);
+    assert_eq!(solve_in_base("A == A", 65), None);
+    assert!(solve_in_base("A == A", 64).is_some());
+}
+
+#[test]
+fn test_non_ascii_letters() {
+    let s = solve_in_base("ΣΕΝΔ + ΜΟΡΕ == ΜΟΝΕΨ", 10).unwrap();
+    assert_eq!(
+        value("ΣΕΝΔ", &s, 10) + value("ΜΟΡΕ", &s, 10),
+        value("ΜΟΝΕΨ", &s, 10)
+    );
+    // Case matters, so these are different letters
+    let s = solve_in_base("A + a == b", 10).unwrap();
+    assert_ne!(s[&'A'], s[&'a']);
+}
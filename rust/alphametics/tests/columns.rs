@@ -0,0 +1,19 @@
+use alphametics::solve;
+
+#[test]
+fn test_malformed_puzzles_have_no_solution() {
+    for puzzle in ["SEND + MORE", "SEND + == MONEY", "SEND + MORE == MON3Y", ""] {
+        assert_eq!(solve(puzzle), None, "{puzzle}");
+    }
+}
+
+#[test]
+fn test_more_than_ten_letters_have_no_solution() {
+    assert_eq!(solve("ABCDEF + GHIJK == LABCDE"), None);
+}
+
+#[test]
+fn test_carry_out_of_the_widest_column() {
+    let solution = solve("A + A + A + A + A + A + A + A + A + A + A + B == BCC").unwrap();
+    assert_eq!((solution[&'A'], solution[&'B'], solution[&'C']), (9, 1, 0));
+}
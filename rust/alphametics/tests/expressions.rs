@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use alphametics::solve;
+
+fn value(word: &str, solution: &HashMap<char, u8>) -> i64 {
+    word.chars()
+        .fold(0, |value, c| value * 10 + solution[&c] as i64)
+}
+
+fn distinct(solution: &HashMap<char, u8>) -> bool {
+    let mut digits = solution.values().collect::<Vec<_>>();
+    digits.sort();
+    digits.dedup();
+    digits.len() == solution.len()
+}
+
+#[test]
+fn test_subtraction() {
+    let s = solve("MONEY - MORE == SEND").unwrap();
+    assert!(distinct(&s));
+    assert_eq!(s[&'M'], 1);
+    assert_eq!(value("MONEY", &s) - value("MORE", &s), value("SEND", &s));
+}
+
+#[test]
+fn test_multiplication() {
+    let s = solve("ABC * DE == FGHIJ").unwrap();
+    assert!(distinct(&s));
+    assert_eq!(value("ABC", &s) * value("DE", &s), value("FGHIJ", &s));
+}
+
+#[test]
+fn test_parentheses_and_numbers() {
+    let s = solve("(AB + C) * 2 == DE - (F)").unwrap();
+    assert!(distinct(&s));
+    assert_eq!(
+        (value("AB", &s) + value("C", &s)) * 2,
+        value("DE", &s) - value("F", &s)
+    );
+    assert_eq!(solve("1 + 1 == 2"), Some(HashMap::new()));
+    assert_eq!(solve("1 + 1 == 3"), None);
+}
+
+#[test]
+fn test_equations_share_letters() {
+    let s = solve("SEND + MORE == MONEY; D + E == Y + 10").unwrap();
+    assert_eq!(value("SEND", &s) + value("MORE", &s), value("MONEY", &s));
+    assert_eq!(s[&'D'] + s[&'E'], s[&'Y'] + 10);
+
+    let s = solve("A * B == CD; C + D == E").unwrap();
+    assert!(distinct(&s));
+    assert_eq!(value("A", &s) * value("B", &s), value("CD", &s));
+    assert_eq!(s[&'C'] + s[&'D'], s[&'E']);
+}
+
+#[test]
+fn test_chained_equations() {
+    let s = solve("AB + C == DE == FG - H;").unwrap();
+    let de = value("DE", &s);
+    assert_eq!(value("AB", &s) + value("C", &s), de);
+    assert_eq!(value("FG", &s) - value("H", &s), de);
+}
+
+#[test]
+fn test_unary_minus() {
+    let s = solve("-A + BC == -D + EF").unwrap();
+    assert_eq!(
+        value("BC", &s) - value("A", &s),
+        value("EF", &s) - value("D", &s)
+    );
+}
+
+#[test]
+fn test_impossible_and_malformed_puzzles() {
+    assert_eq!(solve("A - A == B"), None);
+    for puzzle in [
+        "AB * == C",
+        "(A + B == C",
+        "A + B == C)",
+        "A2 + B == C",
+        "A = B",
+        "A + B",
+    ] {
+        assert_eq!(solve(puzzle), None, "{puzzle}");
+    }
+}
@@ -0,0 +1,84 @@
+use alphametics::{is_uniquely_solvable, solve_all, Difficulty, Generator};
+
+const WORDS: &str = "send more money he sees the light no on too late i bb ill a as at it is to";
+
+fn words() -> Vec<&'static str> {
+    WORDS.split_whitespace().collect()
+}
+
+#[test]
+fn test_puzzles_have_one_solution() {
+    let puzzles = Generator::new(words(), 1).take(5).collect::<Vec<_>>();
+    assert_eq!(puzzles.len(), 5);
+    for generated in &puzzles {
+        assert!(is_uniquely_solvable(generated.puzzle()));
+        assert_eq!(
+            solve_all(generated.puzzle()).next().as_ref(),
+            Some(generated.solution())
+        );
+    }
+}
+
+#[test]
+fn test_same_seed_same_puzzles() {
+    let puzzles = |seed| {
+        Generator::new(words(), seed)
+            .take(3)
+            .map(|generated| generated.to_string())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(puzzles(42), puzzles(42));
+    assert_ne!(puzzles(42), puzzles(43));
+}
+
+#[test]
+fn test_puzzles_are_not_repeated() {
+    let puzzles = Generator::new(words(), 5)
+        .take(10)
+        .map(|generated| {
+            let (addends, result) = generated.puzzle().split_once(" == ").unwrap();
+            let mut addends = addends.split(" + ").collect::<Vec<&str>>();
+            addends.sort();
+            (addends.join(" + "), result.to_string())
+        })
+        .collect::<Vec<_>>();
+    for (i, puzzle) in puzzles.iter().enumerate() {
+        assert!(!puzzles[i + 1..].contains(puzzle));
+    }
+}
+
+#[test]
+fn test_number_of_addends() {
+    let generated = Generator::new(words(), 3)
+        .with_addends(3..=3)
+        .next()
+        .unwrap();
+    assert_eq!(generated.puzzle().matches(" + ").count(), 2);
+}
+
+#[test]
+fn test_difficulty_from_nodes() {
+    let easy = Generator::new(words(), 9).with_difficulty(Difficulty::Easy);
+    for generated in easy.take(3) {
+        assert!(generated.nodes() < 1_000);
+        assert_eq!(generated.difficulty(), Difficulty::Easy);
+    }
+    assert_eq!(Difficulty::from_nodes(6465), Difficulty::Medium);
+    assert!(Difficulty::Easy < Difficulty::Hard);
+}
+
+#[test]
+fn test_words_from_a_file() {
+    let path = std::env::temp_dir().join("alphametics-generate-words.txt");
+    std::fs::write(&path, "I\nBB\nILL\nit's\n").unwrap();
+    let mut generator = Generator::from_file(&path, 0).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let generated = generator.next().unwrap();
+    assert!(["I + BB == ILL", "BB + I == ILL"].contains(&generated.puzzle()));
+    assert!(generator.next().is_none());
+}
+
+#[test]
+fn test_too_few_words() {
+    assert!(Generator::new(["A", "B"], 0).next().is_none());
+}
@@ -0,0 +1,99 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use alphametics::{solve_in_base, CancellationToken, Outcome, Solver};
+
+// In base 12 this has no solution, which only shows once every letter has a digit
+const HARD: &str = "A * B * C * D * E * F * G * H * I * J * K * L == 1";
+
+#[test]
+fn test_solves_like_solve() {
+    let puzzle = "SEND + MORE == MONEY";
+    for threads in [1, 2, 8] {
+        assert_eq!(
+            Solver::new().with_threads(threads).solve(puzzle),
+            Ok(Outcome::Solved(solve_in_base(puzzle, 10).unwrap()))
+        );
+    }
+    assert_eq!(
+        Solver::new().solve("A + A == B + B; A == C"),
+        Ok(Outcome::NoSolution)
+    );
+    assert_eq!(
+        Solver::new().solve("1 + 1 == 2"),
+        Ok(Outcome::Solved(Default::default()))
+    );
+    assert!(Solver::new().solve("A +").is_err());
+}
+
+#[test]
+fn test_other_bases() {
+    let solver = Solver::new().with_base(16);
+    let Ok(Outcome::Solved(solution)) = solver.solve("SEND + MORE == MONEY") else {
+        panic!("no solution");
+    };
+    assert!(solution.values().any(|&digit| digit >= 10));
+}
+
+#[test]
+fn test_cancellation() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let solver = Solver::new()
+        .with_base(12)
+        .with_threads(4)
+        .with_cancellation(token.clone());
+    assert_eq!(solver.solve(HARD), Ok(Outcome::Cancelled));
+    assert!(token.is_cancelled());
+}
+
+#[test]
+fn test_cancelling_after_the_search_ends() {
+    // The last progress report comes once the search has ended
+    let token = CancellationToken::new();
+    let calls = AtomicU64::new(0);
+    let solver = Solver::new()
+        .with_threads(1)
+        .with_cancellation(token.clone())
+        .with_progress(|_| {
+            if calls.fetch_add(1, Ordering::Relaxed) == 1 {
+                token.cancel();
+            }
+        });
+    assert_eq!(
+        solver.solve("A + A == B + B; A == C"),
+        Ok(Outcome::NoSolution)
+    );
+    assert!(token.is_cancelled());
+}
+
+#[test]
+fn test_timeout() {
+    let solver = Solver::new()
+        .with_base(12)
+        .with_timeout(Duration::from_millis(20));
+    assert_eq!(solver.solve(HARD), Ok(Outcome::TimedOut));
+}
+
+#[test]
+fn test_progress() {
+    let calls = AtomicU64::new(0);
+    let last = Mutex::new(0);
+    let solver = Solver::new().with_threads(2).with_progress(|nodes| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        *last.lock().unwrap() = nodes;
+    });
+    assert_eq!(
+        solver.solve("A * B * C * D * E * F * G == 1"),
+        Ok(Outcome::NoSolution)
+    );
+    drop(solver);
+
+    assert!(calls.into_inner() > 1);
+    assert!(last.into_inner().unwrap() > 4096);
+}
@@ -0,0 +1,116 @@
+use alphametics::{try_solve, try_solve_all_in_base, Expected, ParseError};
+
+#[test]
+fn test_missing_equals() {
+    assert_eq!(
+        try_solve("SEND + MORE"),
+        Err(ParseError::Expected {
+            position: 11,
+            expected: Expected::Equals
+        })
+    );
+    assert_eq!(
+        try_solve("A + B = C"),
+        Err(ParseError::UnexpectedChar {
+            position: 6,
+            found: '='
+        })
+    );
+}
+
+#[test]
+fn test_empty_terms() {
+    for (puzzle, position) in [
+        ("A + == B", 4),
+        ("A ++ B == C", 3),
+        ("A + B == ", 9),
+        ("", 0),
+    ] {
+        assert_eq!(
+            try_solve(puzzle),
+            Err(ParseError::Expected {
+                position,
+                expected: Expected::Term
+            }),
+            "{puzzle}"
+        );
+    }
+}
+
+#[test]
+fn test_digits_in_words() {
+    assert_eq!(
+        try_solve("SE3ND + MORE == MONEY"),
+        Err(ParseError::MixedWord {
+            position: 0,
+            word: "SE3ND".into()
+        })
+    );
+    assert_eq!(
+        try_solve_all_in_base("A + 12 == B", 2).err(),
+        Some(ParseError::BadNumber {
+            position: 4,
+            number: "12".into()
+        })
+    );
+}
+
+#[test]
+fn test_parentheses() {
+    assert_eq!(
+        try_solve("A * (B + C == D"),
+        Err(ParseError::UnclosedParenthesis { position: 4 })
+    );
+    assert_eq!(
+        try_solve("A + B == C)"),
+        Err(ParseError::Expected {
+            position: 10,
+            expected: Expected::Operator
+        })
+    );
+}
+
+#[test]
+fn test_positions_count_characters() {
+    let error = try_solve("ΑΒ + ΓΔ == ?").unwrap_err();
+    assert_eq!(error.position(), Some(11));
+    assert_eq!(error.to_string(), "unexpected `?` at 11");
+}
+
+#[test]
+fn test_puzzles_which_cant_be_solved_for() {
+    assert_eq!(
+        try_solve("ABCDEFGHIJK == KJIHGFEDCBA").map(|_| ()),
+        Err(ParseError::TooManyLetters {
+            letters: 11,
+            base: 10
+        })
+    );
+    assert_eq!(
+        try_solve_all_in_base("A == A", 100).err(),
+        Some(ParseError::BadBase(100))
+    );
+    assert_eq!(
+        try_solve("ABCDEFGHIJ * ABCDEFGHIJ * ABCDEFGHIJ * ABCDEFGHIJ == A").map(|_| ()),
+        Err(ParseError::TooLarge)
+    );
+    assert_eq!(ParseError::TooLarge.position(), None);
+}
+
+#[test]
+fn test_valid_puzzles() {
+    assert_eq!(try_solve("A + B == A"), Ok(None));
+    assert!(try_solve("SEND + MORE == MONEY").unwrap().is_some());
+}
+
+#[test]
+fn test_constants_which_overflow() {
+    assert_eq!(
+        try_solve("170141183460469231731687303715884105727 + 1 == A"),
+        Err(ParseError::TooLarge)
+    );
+    assert_eq!(
+        try_solve("A == -170141183460469231731687303715884105727 - 1"),
+        Err(ParseError::TooLarge)
+    );
+}
@@ -0,0 +1,36 @@
+use alphametics::{is_uniquely_solvable, solve_all};
+
+#[test]
+fn test_every_solution_is_found_once() {
+    let solutions = solve_all("A + B == C").collect::<Vec<_>>();
+    // Two different digits from 1 adding up to at most 9
+    assert_eq!(solutions.len(), 32);
+    assert!(solutions
+        .iter()
+        .all(|s| s[&'A'] + s[&'B'] == s[&'C'] && s[&'A'] != s[&'B']));
+    for (i, solution) in solutions.iter().enumerate() {
+        assert!(!solutions[i + 1..].contains(solution));
+    }
+}
+
+#[test]
+fn test_solutions_are_lazy() {
+    let mut solutions = solve_all("A == A");
+    assert!(solutions.next().is_some());
+    assert_eq!(solutions.count(), 8);
+}
+
+#[test]
+fn test_unique_puzzles() {
+    assert!(is_uniquely_solvable("SEND + MORE == MONEY"));
+    assert!(is_uniquely_solvable("I + BB == ILL"));
+    assert!(!is_uniquely_solvable("A + B == C"));
+    assert!(!is_uniquely_solvable("A == B"));
+    assert!(!is_uniquely_solvable("A + B =="));
+}
+
+#[test]
+fn test_puzzles_without_letters() {
+    assert_eq!(solve_all("2 * 3 == 6").count(), 1);
+    assert_eq!(solve_all("2 * 3 == 7").count(), 0);
+}
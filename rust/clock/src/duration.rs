@@ -0,0 +1,50 @@
+use std::{ops::Neg, time::Duration};
+
+/// A duration which may be negative, such as the difference between two clocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SignedDuration {
+    nanos: i64,
+}
+
+impl SignedDuration {
+    pub const ZERO: SignedDuration = SignedDuration { nanos: 0 };
+
+    pub fn from_nanos(nanos: i64) -> Self {
+        Self { nanos }
+    }
+
+    /// Saturates at the largest durations an `i64` of nanoseconds holds, about 292 years.
+    pub fn from_secs(secs: i64) -> Self {
+        Self::from_nanos(secs.saturating_mul(1_000_000_000))
+    }
+
+    pub fn from_mins(mins: i64) -> Self {
+        Self::from_secs(mins.saturating_mul(60))
+    }
+
+    pub fn as_nanos(&self) -> i64 {
+        self.nanos
+    }
+
+    /// The whole seconds, rounded towards zero.
+    pub fn as_secs(&self) -> i64 {
+        self.nanos / 1_000_000_000
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.nanos < 0
+    }
+
+    /// The length of the duration whichever way it goes.
+    pub fn unsigned_abs(&self) -> Duration {
+        Duration::from_nanos(self.nanos.unsigned_abs())
+    }
+}
+
+impl Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    fn neg(self) -> SignedDuration {
+        SignedDuration::from_nanos(self.nanos.saturating_neg())
+    }
+}
@@ -0,0 +1,72 @@
+// Ways of storing clocks: two bytes holding the minutes since midnight, and with the `serde`
+// feature a string such as `"08:30"`.
+
+use crate::{Clock, HOURS_IN_DAYS, MINS_IN_HOURS, NANOS_IN_MINS};
+
+impl Clock {
+    /// The minutes since midnight as a big endian `u16`, the seconds are left out.
+    pub fn to_bytes(&self) -> [u8; 2] {
+        ((self.nanos / NANOS_IN_MINS) as u16).to_be_bytes()
+    }
+
+    /// Reads `to_bytes`, `None` for more minutes than a day has.
+    pub fn from_bytes(bytes: [u8; 2]) -> Option<Clock> {
+        let minutes = u16::from_be_bytes(bytes) as i64;
+        (minutes < MINS_IN_HOURS * HOURS_IN_DAYS)
+            .then(|| Clock::from_nanos(minutes * NANOS_IN_MINS))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use core::fmt;
+
+    use serde::{
+        de::{self, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use crate::{Clock, HOURS_IN_DAYS, ISO_8601, MINS_IN_HOURS, NANOS_IN_MINS};
+
+    /// `"HH:MM"`, with seconds and their fraction when there are any so nothing is lost.
+    impl Serialize for Clock {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.nanos % NANOS_IN_MINS {
+                0 => serializer.collect_str(self),
+                _ => serializer.collect_str(&self.format(ISO_8601)),
+            }
+        }
+    }
+
+    /// A string in any format `Clock` parses, or a number of minutes which wraps around the
+    /// day as in `Clock::new`.
+    impl<'de> Deserialize<'de> for Clock {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(ClockVisitor)
+        }
+    }
+
+    struct ClockVisitor;
+
+    impl Visitor<'_> for ClockVisitor {
+        type Value = Clock;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a time such as \"08:30\" or a number of minutes")
+        }
+
+        fn visit_str<E: de::Error>(self, text: &str) -> Result<Clock, E> {
+            text.parse().map_err(E::custom)
+        }
+
+        fn visit_i64<E: de::Error>(self, minutes: i64) -> Result<Clock, E> {
+            let minutes = minutes.rem_euclid(MINS_IN_HOURS * HOURS_IN_DAYS);
+            Ok(Clock::from_nanos(minutes * NANOS_IN_MINS))
+        }
+
+        fn visit_u64<E: de::Error>(self, minutes: u64) -> Result<Clock, E> {
+            let minutes = minutes % (MINS_IN_HOURS * HOURS_IN_DAYS) as u64;
+            self.visit_i64(minutes as i64)
+        }
+    }
+}
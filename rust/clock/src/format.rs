@@ -0,0 +1,92 @@
+// Writes clocks with strftime-like patterns:
+//
+//     %H  hours 00-23        %I  hours 01-12        %p  AM or PM     %P  am or pm
+//     %M  minutes            %S  seconds            %R  %H:%M        %T  %H:%M:%S
+//     %f  nanoseconds        %3f %6f %9f            %.f  a point and as many digits as needed,
+//         9 digits               that many digits         nothing for whole seconds
+//     %r  %I:%M:%S %p        %%  a `%`
+//
+// Anything else is written as it is.
+
+use core::fmt;
+
+use crate::Clock;
+
+/// `08:30 PM`
+pub const TWELVE_HOUR: &str = "%I:%M %p";
+/// `20:30:15`
+pub const WITH_SECONDS: &str = "%T";
+/// `08:30:15 PM`
+pub const TWELVE_HOUR_WITH_SECONDS: &str = "%r";
+/// `20:30:15` or `20:30:15.25`
+pub const ISO_8601: &str = "%T%.f";
+
+/// A clock written with a pattern, from `Clock::format`.
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'a> {
+    clock: Clock,
+    pattern: &'a str,
+}
+
+impl Clock {
+    pub fn format(self, pattern: &str) -> Formatted<'_> {
+        Formatted {
+            clock: self,
+            pattern,
+        }
+    }
+}
+
+impl fmt::Display for Formatted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let clock = self.clock;
+        let mut text = String::new();
+        let mut chars = self.pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                text.push(c);
+                continue;
+            }
+            let Some(spec) = chars.next() else {
+                text.push('%');
+                break;
+            };
+            let twelve = match clock.hours() % 12 {
+                0 => 12,
+                hours => hours,
+            };
+            let pm = clock.hours() >= 12;
+            let written = match spec {
+                'H' => format!("{:02}", clock.hours()),
+                'I' => format!("{twelve:02}"),
+                'M' => format!("{:02}", clock.minutes()),
+                'S' => format!("{:02}", clock.seconds()),
+                'p' => if pm { "PM" } else { "AM" }.to_string(),
+                'P' => if pm { "pm" } else { "am" }.to_string(),
+                'R' => format!("{:02}:{:02}", clock.hours(), clock.minutes()),
+                'T' => clock.format("%H:%M:%S").to_string(),
+                'r' => clock.format("%I:%M:%S %p").to_string(),
+                'f' => format!("{:09}", clock.nanoseconds()),
+                '%' => "%".to_string(),
+                digits @ ('3' | '6' | '9') if chars.clone().next() == Some('f') => {
+                    chars.next();
+                    let digits = digits.to_digit(10).unwrap_or(9);
+                    let nanos = clock.nanoseconds() / 10u32.pow(9 - digits);
+                    format!("{nanos:0width$}", width = digits as usize)
+                }
+                '.' if chars.clone().next() == Some('f') => {
+                    chars.next();
+                    match clock.nanoseconds() {
+                        0 => String::new(),
+                        nanos => format!(".{}", format!("{nanos:09}").trim_end_matches('0')),
+                    }
+                }
+                other => format!("%{other}"),
+            };
+            text.push_str(&written);
+        }
+
+        f.pad(&text)
+    }
+}
@@ -1,41 +1,167 @@
-// Clock that uses No Hours Version
+// Clock that uses No Hours Version, counting nanoseconds into the day instead of minutes
 
 use core::fmt;
+use std::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::Duration,
+};
 
-const MINS_IN_HOURS: i32 = 60;
-const HOURS_IN_DAYS: i32 = 24;
-const MINS_IN_DAYS: i32 = MINS_IN_HOURS * HOURS_IN_DAYS;
+mod duration;
+mod encoding;
+mod format;
+mod parse;
+mod range;
+mod schedule;
+mod zone;
 
-#[derive(Debug, PartialEq)]
+pub use duration::SignedDuration;
+pub use format::{Formatted, ISO_8601, TWELVE_HOUR, TWELVE_HOUR_WITH_SECONDS, WITH_SECONDS};
+pub use parse::{Field, ParseError};
+pub use range::TimeRange;
+pub use schedule::{Schedule, Weekday};
+pub use zone::FixedOffset;
+
+const MINS_IN_HOURS: i64 = 60;
+const HOURS_IN_DAYS: i64 = 24;
+const SECS_IN_MINS: i64 = 60;
+const SECS_IN_DAYS: i64 = SECS_IN_MINS * MINS_IN_HOURS * HOURS_IN_DAYS;
+const NANOS_IN_SECS: i64 = 1_000_000_000;
+const NANOS_IN_MINS: i64 = NANOS_IN_SECS * SECS_IN_MINS;
+const NANOS_IN_DAYS: i64 = NANOS_IN_SECS * SECS_IN_DAYS;
+
+/// A time of day which wraps around at midnight. Clocks sort from 00:00 to 23:59.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Clock {
-    minutes: i32,
+    // Always below a day
+    nanos: i64,
 }
 
 impl Clock {
     pub fn new(hours: i32, minutes: i32) -> Self {
-        // unimplemented!("Construct a new Clock from {hours} hours and {minutes} minutes");
+        Self::from_hms(hours, minutes, 0)
+    }
+
+    /// Like `new` with seconds, any of which may be negative or past their largest value.
+    pub fn from_hms(hours: i32, minutes: i32, seconds: i32) -> Self {
+        Self::from_hms_nano(hours, minutes, seconds, 0)
+    }
+
+    pub fn from_hms_nano(hours: i32, minutes: i32, seconds: i32, nanos: i64) -> Self {
+        // Fits in an i64 for any i32, unlike the nanoseconds of the hours
+        let seconds =
+            (hours as i64 * MINS_IN_HOURS + minutes as i64) * SECS_IN_MINS + seconds as i64;
+        Self::from_nanos(
+            seconds.rem_euclid(SECS_IN_DAYS) * NANOS_IN_SECS + nanos.rem_euclid(NANOS_IN_DAYS),
+        )
+    }
+
+    // Wraps any number of nanoseconds which doesn't overflow into the day
+    fn from_nanos(nanos: i64) -> Self {
         Self {
-            minutes: (minutes + (hours * MINS_IN_HOURS)).rem_euclid(MINS_IN_DAYS),
+            nanos: nanos.rem_euclid(NANOS_IN_DAYS),
         }
     }
 
     pub fn add_minutes(&self, minutes: i32) -> Self {
-        // unimplemented!("Add {minutes} minutes to existing Clock time");
-        Self {
-            minutes: (self.minutes + minutes).rem_euclid(MINS_IN_DAYS),
-        }
+        Self::from_nanos(
+            self.nanos + (minutes as i64).rem_euclid(MINS_IN_HOURS * HOURS_IN_DAYS) * NANOS_IN_MINS,
+        )
+    }
+
+    pub fn add_seconds(&self, seconds: i32) -> Self {
+        Self::from_nanos(self.nanos + (seconds as i64).rem_euclid(SECS_IN_DAYS) * NANOS_IN_SECS)
+    }
+
+    pub fn hours(&self) -> u32 {
+        (self.nanos / (NANOS_IN_MINS * MINS_IN_HOURS)) as u32
+    }
+
+    /// The minutes past the hour.
+    pub fn minutes(&self) -> u32 {
+        (self.nanos / NANOS_IN_MINS % MINS_IN_HOURS) as u32
+    }
+
+    /// The seconds past the minute.
+    pub fn seconds(&self) -> u32 {
+        (self.nanos / NANOS_IN_SECS % SECS_IN_MINS) as u32
+    }
+
+    /// The nanoseconds past the second.
+    pub fn nanoseconds(&self) -> u32 {
+        (self.nanos % NANOS_IN_SECS) as u32
+    }
+
+    /// The time since midnight.
+    pub fn since_midnight(&self) -> Duration {
+        Duration::from_nanos(self.nanos as u64)
     }
 }
 
 impl fmt::Display for Clock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:02}:{:02}",
-            self.minutes
-                .div_euclid(MINS_IN_HOURS)
-                .rem_euclid(HOURS_IN_DAYS),
-            self.minutes.rem_euclid(MINS_IN_HOURS)
-        )
+        write!(f, "{:02}:{:02}", self.hours(), self.minutes())
+    }
+}
+
+// Only the part of a duration less than a day moves the clock
+fn nanos_in_day(duration: Duration) -> i64 {
+    (duration.as_nanos() % NANOS_IN_DAYS as u128) as i64
+}
+
+impl Add<Duration> for Clock {
+    type Output = Clock;
+
+    fn add(self, duration: Duration) -> Clock {
+        Clock::from_nanos(self.nanos + nanos_in_day(duration))
+    }
+}
+
+impl Sub<Duration> for Clock {
+    type Output = Clock;
+
+    fn sub(self, duration: Duration) -> Clock {
+        Clock::from_nanos(self.nanos - nanos_in_day(duration))
+    }
+}
+
+impl AddAssign<Duration> for Clock {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl SubAssign<Duration> for Clock {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl Add<SignedDuration> for Clock {
+    type Output = Clock;
+
+    fn add(self, duration: SignedDuration) -> Clock {
+        Clock::from_nanos(self.nanos + duration.as_nanos() % NANOS_IN_DAYS)
+    }
+}
+
+impl Sub<SignedDuration> for Clock {
+    type Output = Clock;
+
+    fn sub(self, duration: SignedDuration) -> Clock {
+        Clock::from_nanos(self.nanos - duration.as_nanos() % NANOS_IN_DAYS)
+    }
+}
+
+/// The shortest way around the dial from `other` to `self`, more than 12 hours back and up to
+/// 12 hours ahead, so that `other + (self - other) == self`.
+impl Sub<Clock> for Clock {
+    type Output = SignedDuration;
+
+    fn sub(self, other: Clock) -> SignedDuration {
+        let ahead = (self.nanos - other.nanos).rem_euclid(NANOS_IN_DAYS);
+        SignedDuration::from_nanos(match ahead > NANOS_IN_DAYS / 2 {
+            true => ahead - NANOS_IN_DAYS,
+            false => ahead,
+        })
     }
 }
@@ -0,0 +1,214 @@
+// Reads the ways people and machines write times of day:
+//
+//     08:30   8:30   08:30:15.250    hours, minutes, seconds and a fraction with colons
+//     0830   083015                  ISO 8601 basic, without colons
+//     8:30 pm   8 PM   12:05 a.m.    the 12 hour clock
+//     T08:30:15Z   08:30+05:30       ISO 8601 with a `T` in front or an offset after
+//
+// ISO 8601 writes the midnight at the end of a day as 24:00, which is read as 00:00.
+
+use core::fmt;
+use std::str::FromStr;
+
+use crate::{Clock, FixedOffset};
+
+/// Why a time or an offset can't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    /// The text isn't a time in any of the formats `Clock` reads.
+    BadFormat(String),
+    OutOfRange {
+        field: Field,
+        value: u32,
+    },
+    BadOffset(String),
+}
+
+/// The part of a time which is out of range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Hour,
+    /// The hour of a time with AM or PM, from 1 to 12.
+    TwelveHour,
+    Minute,
+    Second,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "no time given"),
+            ParseError::BadFormat(text) => write!(f, "`{text}` is not a time"),
+            ParseError::OutOfRange { field, value } => {
+                let (name, low, high) = match field {
+                    Field::Hour => ("hour", 0, 23),
+                    Field::TwelveHour => ("hour", 1, 12),
+                    Field::Minute => ("minute", 0, 59),
+                    Field::Second => ("second", 0, 59),
+                };
+                write!(f, "{name} {value} is not between {low} and {high}")
+            }
+            ParseError::BadOffset(text) => write!(f, "`{text}` is not an offset from UTC"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Clock {
+    /// Reads a time with the offset from UTC ISO 8601 times may end with.
+    pub fn parse_with_offset(input: &str) -> Result<(Clock, Option<FixedOffset>), ParseError> {
+        let text = input.trim();
+        if text.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let bad_format = || ParseError::BadFormat(text.to_string());
+
+        let (rest, meridiem) = split_meridiem(text);
+        let (rest, offset) = match meridiem {
+            Some(_) => (rest, None),
+            None => split_offset(rest)?,
+        };
+        let rest = rest.strip_prefix(['T', 't']).unwrap_or(rest);
+
+        let (rest, nanos) = match rest.split_once(['.', ',']) {
+            Some((rest, fraction)) => (rest, parse_fraction(fraction).ok_or_else(bad_format)?),
+            None => (rest, 0),
+        };
+        let fields = match rest.contains(':') {
+            true => {
+                let mut fields = rest.split(':');
+                let hours = fields.next().and_then(|hours| number(hours, 1));
+                let rest = fields.map(|field| number(field, 2));
+                [hours]
+                    .into_iter()
+                    .chain(rest)
+                    .collect::<Option<Vec<u32>>>()
+            }
+            false => match rest.len() {
+                1 | 2 => number(rest, rest.len()).map(|hours| vec![hours]),
+                4 | 6 => (0..rest.len())
+                    .step_by(2)
+                    .map(|i| rest.get(i..i + 2).and_then(|field| number(field, 2)))
+                    .collect(),
+                _ => None,
+            },
+        };
+        let (hours, minutes, seconds) = match fields.ok_or_else(bad_format)?[..] {
+            [hours] => (hours, 0, 0),
+            [hours, minutes] => (hours, minutes, 0),
+            [hours, minutes, seconds] => (hours, minutes, seconds),
+            _ => return Err(bad_format()),
+        };
+        // A fraction only goes with seconds
+        if nanos > 0 && !(rest.len() == 6 || rest.matches(':').count() == 2) {
+            return Err(bad_format());
+        }
+
+        let out_of_range = |field, value| Err(ParseError::OutOfRange { field, value });
+        if minutes > 59 {
+            return out_of_range(Field::Minute, minutes);
+        } else if seconds > 59 {
+            return out_of_range(Field::Second, seconds);
+        }
+        let hours = match meridiem {
+            Some(_) if !(1..=12).contains(&hours) => return out_of_range(Field::TwelveHour, hours),
+            Some(pm) => hours % 12 + if pm { 12 } else { 0 },
+            None if hours == 24 && minutes == 0 && seconds == 0 && nanos == 0 => 0,
+            None if hours > 23 => return out_of_range(Field::Hour, hours),
+            None => hours,
+        };
+
+        let clock = Clock::from_hms_nano(hours as i32, minutes as i32, seconds as i32, nanos);
+        Ok((clock, offset))
+    }
+}
+
+impl FromStr for Clock {
+    type Err = ParseError;
+
+    /// Reads a time in any of the formats above, an offset from UTC is left out.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Clock::parse_with_offset(input).map(|(clock, _)| clock)
+    }
+}
+
+impl FromStr for FixedOffset {
+    type Err = ParseError;
+
+    /// Reads `Z` for UTC or a sign with hours and minutes, as `+05:30`, `-0800` or `+01`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let text = input.trim();
+        let bad_offset = || ParseError::BadOffset(text.to_string());
+        if text.eq_ignore_ascii_case("z") {
+            return Ok(FixedOffset::UTC);
+        }
+
+        let (negative, rest) = match text.split_at_checked(1) {
+            Some(("+", rest)) => (false, rest),
+            Some(("-", rest)) => (true, rest),
+            _ => return Err(bad_offset()),
+        };
+        let (hours, minutes) = match (rest.len(), rest.split_once(':')) {
+            (_, Some((hours, minutes))) => (number(hours, 2), number(minutes, 2)),
+            (2, None) => (number(rest, 2), Some(0)),
+            (4, None) => match (rest.get(..2), rest.get(2..)) {
+                (Some(hours), Some(minutes)) => (number(hours, 2), number(minutes, 2)),
+                _ => (None, None),
+            },
+            _ => (None, None),
+        };
+        let (Some(hours), Some(minutes)) = (hours, minutes) else {
+            return Err(bad_offset());
+        };
+
+        let seconds = (hours * 3600 + minutes * 60) as i32;
+        let offset = match (minutes < 60, negative) {
+            (false, _) => None,
+            (true, true) => FixedOffset::west(seconds),
+            (true, false) => FixedOffset::east(seconds),
+        };
+        offset.ok_or_else(bad_offset)
+    }
+}
+
+// Takes `am`, `pm`, `a.m.` or `p.m.` in any case off the end, true for pm
+fn split_meridiem(text: &str) -> (&str, Option<bool>) {
+    let lower = text.to_ascii_lowercase();
+    for (suffix, pm) in [("am", false), ("pm", true), ("a.m.", false), ("p.m.", true)] {
+        if lower.ends_with(suffix) && text.is_char_boundary(text.len() - suffix.len()) {
+            return (text[..text.len() - suffix.len()].trim_end(), Some(pm));
+        }
+    }
+    (text, None)
+}
+
+// Takes a `Z` or an offset starting with a sign off the end
+fn split_offset(text: &str) -> Result<(&str, Option<FixedOffset>), ParseError> {
+    match text.rfind(['Z', 'z', '+', '-']) {
+        Some(at) => Ok((&text[..at], Some(text[at..].parse()?))),
+        None => Ok((text, None)),
+    }
+}
+
+// A number of exactly `digits` digits, or 1 to 2 for `digits` 1
+fn number(text: &str, digits: usize) -> Option<u32> {
+    let length = match digits {
+        1 => 1..=2,
+        digits => digits..=digits,
+    };
+    if !length.contains(&text.len()) || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+// Up to 9 digits after the decimal point, as nanoseconds
+fn parse_fraction(fraction: &str) -> Option<i64> {
+    if !(1..=9).contains(&fraction.len()) || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let nanos = fraction.parse::<i64>().ok()?;
+    Some(nanos * 10i64.pow(9 - fraction.len() as u32))
+}
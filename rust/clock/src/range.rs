@@ -0,0 +1,134 @@
+// A stretch of the dial from one clock to another, going forward and past midnight if the end
+// comes before the start. Laid out on a line two days long, the ranges which may meet a range
+// are the other one a day before, the same day or a day after.
+
+use core::fmt;
+use std::{str::FromStr, time::Duration};
+
+use crate::{Clock, ParseError, NANOS_IN_DAYS};
+
+/// The times from `start` up to but not including `end`, such as 22:00-02:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeRange {
+    start: Clock,
+    // From 1 nanosecond to a day
+    length: i64,
+}
+
+impl TimeRange {
+    /// A range from a clock round to the same clock is the whole day.
+    pub fn new(start: Clock, end: Clock) -> Self {
+        Self::from_length(
+            start.nanos,
+            (end.nanos - start.nanos).rem_euclid(NANOS_IN_DAYS),
+        )
+    }
+
+    pub fn whole_day() -> Self {
+        Self::from_length(0, NANOS_IN_DAYS)
+    }
+
+    // Whole days all start at midnight so they're equal, 0 is a whole day too
+    fn from_length(start: i64, length: i64) -> Self {
+        let start = Clock::from_nanos(start);
+        match length {
+            0 | NANOS_IN_DAYS.. => Self {
+                start: Clock::from_nanos(0),
+                length: NANOS_IN_DAYS,
+            },
+            length => Self { start, length },
+        }
+    }
+
+    pub fn start(&self) -> Clock {
+        self.start
+    }
+
+    pub fn end(&self) -> Clock {
+        Clock::from_nanos(self.start.nanos + self.length)
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.length as u64)
+    }
+
+    pub fn is_whole_day(&self) -> bool {
+        self.length == NANOS_IN_DAYS
+    }
+
+    /// Whether the range goes past midnight, so its end is before its start.
+    pub fn wraps_midnight(&self) -> bool {
+        self.start.nanos + self.length > NANOS_IN_DAYS
+    }
+
+    pub fn contains(&self, clock: Clock) -> bool {
+        (clock.nanos - self.start.nanos).rem_euclid(NANOS_IN_DAYS) < self.length
+    }
+
+    pub fn overlaps(&self, other: &TimeRange) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The times in both ranges, which can be two ranges when both wrap midnight, such as
+    /// 20:00-04:00 and 02:00-22:00 sharing 02:00-04:00 and 20:00-22:00.
+    pub fn intersection(&self, other: &TimeRange) -> Vec<TimeRange> {
+        if self.is_whole_day() {
+            return vec![*other];
+        } else if other.is_whole_day() {
+            return vec![*self];
+        }
+
+        let (start, end) = self.span();
+        other
+            .spans()
+            .into_iter()
+            .map(|(other_start, other_end)| (start.max(other_start), end.min(other_end)))
+            .filter(|(start, end)| start < end)
+            .map(|(start, end)| Self::from_length(start, end - start))
+            .collect()
+    }
+
+    /// The times in either range, `None` when there's a gap between them.
+    pub fn union(&self, other: &TimeRange) -> Option<TimeRange> {
+        let (mut start, mut end) = self.span();
+        let mut joined = false;
+        for (other_start, other_end) in other.spans() {
+            if other_start <= end && start <= other_end {
+                (start, end) = (start.min(other_start), end.max(other_end));
+                joined = true;
+            }
+        }
+        // Going all the way round makes a whole day, for which `from_length` takes 0
+        joined.then(|| Self::from_length(start, (end - start).min(NANOS_IN_DAYS)))
+    }
+
+    // Where the range is on a line starting at the midnight before it
+    fn span(&self) -> (i64, i64) {
+        (self.start.nanos, self.start.nanos + self.length)
+    }
+
+    // The range the day before, the same day and the day after
+    fn spans(&self) -> [(i64, i64); 3] {
+        let (start, end) = self.span();
+        [-1, 0, 1].map(|day| (start + day * NANOS_IN_DAYS, end + day * NANOS_IN_DAYS))
+    }
+}
+
+impl fmt::Display for TimeRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("{}-{}", self.start, self.end()))
+    }
+}
+
+impl FromStr for TimeRange {
+    type Err = ParseError;
+
+    /// Reads two times as `Clock` does separated by `-` or `–`, such as `22:00-02:00`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (start, end) = input
+            .split_once('–')
+            .or_else(|| input.split_once('-'))
+            .ok_or_else(|| ParseError::BadFormat(input.trim().to_string()))?;
+        Ok(TimeRange::new(start.parse()?, end.parse()?))
+    }
+}
@@ -0,0 +1,152 @@
+// A week of opening hours. Every range is laid out on a line a week long from Monday midnight,
+// those going past Sunday midnight carrying on from the start, and ranges which meet are joined
+// so an opening is only where the schedule goes from closed to open.
+
+use core::fmt;
+
+use crate::{Clock, TimeRange, NANOS_IN_DAYS};
+
+const NANOS_IN_WEEKS: i64 = NANOS_IN_DAYS * 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// From Monday to Sunday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// The day after, Monday after Sunday.
+    pub fn succ(self) -> Weekday {
+        Weekday::ALL[(self as usize + 1) % 7]
+    }
+
+    /// The day before, Sunday before Monday.
+    pub fn pred(self) -> Weekday {
+        Weekday::ALL[(self as usize + 6) % 7]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("{self:?}"))
+    }
+}
+
+/// Ranges of time recurring every week, each starting on a weekday and going on into the
+/// next day if it wraps midnight.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Schedule {
+    ranges: Vec<(Weekday, TimeRange)>,
+}
+
+impl Schedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_range(mut self, day: Weekday, range: TimeRange) -> Self {
+        self.ranges.push((day, range));
+        self
+    }
+
+    /// Adds `range` on each of `days`.
+    pub fn with_days(self, days: impl IntoIterator<Item = Weekday>, range: TimeRange) -> Self {
+        days.into_iter()
+            .fold(self, |schedule, day| schedule.with_range(day, range))
+    }
+
+    pub fn ranges(&self) -> &[(Weekday, TimeRange)] {
+        &self.ranges
+    }
+
+    pub fn is_open(&self, day: Weekday, clock: Clock) -> bool {
+        let at = week_nanos(day, clock);
+        self.open_spans()
+            .iter()
+            .any(|&(start, end)| (at - start).rem_euclid(NANOS_IN_WEEKS) < end - start)
+    }
+
+    /// The first time from `clock` on `day` on, which may be that time, at which the schedule
+    /// opens after being closed. `None` when it's never open or never closes.
+    pub fn next_opening(&self, day: Weekday, clock: Clock) -> Option<(Weekday, Clock)> {
+        self.next(day, clock, |(start, _)| start)
+    }
+
+    /// The first time from `clock` on `day` on at which the schedule closes after being open.
+    pub fn next_closing(&self, day: Weekday, clock: Clock) -> Option<(Weekday, Clock)> {
+        self.next(day, clock, |(_, end)| end)
+    }
+
+    fn next(
+        &self,
+        day: Weekday,
+        clock: Clock,
+        edge: fn((i64, i64)) -> i64,
+    ) -> Option<(Weekday, Clock)> {
+        let spans = self.open_spans();
+        if spans == [(0, NANOS_IN_WEEKS)] {
+            return None;
+        }
+        let at = week_nanos(day, clock);
+        let next = spans
+            .into_iter()
+            .map(|span| at + (edge(span) - at).rem_euclid(NANOS_IN_WEEKS))
+            .min()?
+            .rem_euclid(NANOS_IN_WEEKS);
+        Some((
+            Weekday::ALL[(next / NANOS_IN_DAYS) as usize],
+            Clock::from_nanos(next % NANOS_IN_DAYS),
+        ))
+    }
+
+    // The times of the week the schedule is open, sorted and joined where they meet. A span
+    // going on past Sunday midnight starts before 0, a schedule open all week is one span.
+    fn open_spans(&self) -> Vec<(i64, i64)> {
+        let mut spans = vec![];
+        for &(day, range) in &self.ranges {
+            let start = week_nanos(day, range.start());
+            let end = start + range.duration().as_nanos() as i64;
+            match end > NANOS_IN_WEEKS {
+                true => spans.extend([(start, NANOS_IN_WEEKS), (0, end - NANOS_IN_WEEKS)]),
+                false => spans.push((start, end)),
+            }
+        }
+        spans.sort();
+
+        let mut joined: Vec<(i64, i64)> = vec![];
+        for (start, end) in spans {
+            match joined.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => joined.push((start, end)),
+            }
+        }
+
+        // Open over Sunday midnight is one span, unless it's open all week
+        if joined.len() > 1 && joined[0].0 == 0 && joined[joined.len() - 1].1 == NANOS_IN_WEEKS {
+            let (start, _) = joined.remove(joined.len() - 1);
+            joined[0].0 = start - NANOS_IN_WEEKS;
+        }
+        joined
+    }
+}
+
+// The time from Monday midnight
+fn week_nanos(day: Weekday, clock: Clock) -> i64 {
+    day as i64 * NANOS_IN_DAYS + clock.nanos
+}
@@ -0,0 +1,77 @@
+use core::fmt;
+
+use crate::{Clock, SECS_IN_DAYS};
+
+/// A time zone a fixed number of seconds ahead of UTC, or behind it when negative,
+/// written as `+05:30`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FixedOffset {
+    seconds: i32,
+}
+
+impl FixedOffset {
+    pub const UTC: FixedOffset = FixedOffset { seconds: 0 };
+
+    /// `None` unless the offset is less than a day either way.
+    pub fn east(seconds: i32) -> Option<Self> {
+        ((seconds as i64).abs() < SECS_IN_DAYS).then_some(Self { seconds })
+    }
+
+    pub fn west(seconds: i32) -> Option<Self> {
+        Self::east(seconds.checked_neg()?)
+    }
+
+    /// An offset of hours and minutes, both behind UTC for negative hours, so `-3, 30`
+    /// is `-03:30`. With no hours the sign comes from the minutes, so `0, -30` is `-00:30`.
+    pub fn from_hm(hours: i32, minutes: i32) -> Option<Self> {
+        let minutes_range = match hours {
+            0 => -59..60,
+            _ => 0..60,
+        };
+        if !minutes_range.contains(&minutes) {
+            return None;
+        }
+        let seconds = hours
+            .checked_abs()?
+            .checked_mul(3600)?
+            .checked_add(minutes.abs() * 60)?;
+        match hours < 0 || minutes < 0 {
+            true => Self::west(seconds),
+            false => Self::east(seconds),
+        }
+    }
+
+    /// The seconds ahead of UTC.
+    pub fn seconds(&self) -> i32 {
+        self.seconds
+    }
+}
+
+impl fmt::Display for FixedOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.seconds < 0 { '-' } else { '+' };
+        let seconds = self.seconds.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", seconds / 3600, seconds / 60 % 60)?;
+        match seconds % 60 {
+            0 => Ok(()),
+            seconds => write!(f, ":{seconds:02}"),
+        }
+    }
+}
+
+impl Clock {
+    /// The time in UTC when a clock in `offset` shows this time.
+    pub fn to_utc(self, offset: FixedOffset) -> Clock {
+        self.add_seconds(-offset.seconds)
+    }
+
+    /// The time a clock in `offset` shows when it's this time in UTC.
+    pub fn in_offset(self, offset: FixedOffset) -> Clock {
+        self.add_seconds(offset.seconds)
+    }
+
+    /// The time in `to` when it's this time in `from`.
+    pub fn convert(self, from: FixedOffset, to: FixedOffset) -> Clock {
+        self.to_utc(from).in_offset(to)
+    }
+}
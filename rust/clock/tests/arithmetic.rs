@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use clock::{Clock, SignedDuration};
+
+#[test]
+fn test_seconds_wrap_like_minutes() {
+    let clock = Clock::from_hms(23, 59, 75);
+    assert_eq!(
+        (clock.hours(), clock.minutes(), clock.seconds()),
+        (0, 0, 15)
+    );
+    assert_eq!(Clock::from_hms(0, 0, -1), Clock::from_hms(23, 59, 59));
+    assert_eq!(Clock::from_hms(1, 2, 0), Clock::new(1, 2));
+    assert_eq!(
+        Clock::from_hms(i32::MAX, i32::MIN, i32::MAX).to_string(),
+        "08:06"
+    );
+}
+
+#[test]
+fn test_nanoseconds() {
+    let clock = Clock::from_hms_nano(10, 0, 0, -1);
+    assert_eq!(clock.to_string(), "09:59");
+    assert_eq!(clock.seconds(), 59);
+    assert_eq!(clock.nanoseconds(), 999_999_999);
+    assert_eq!(Clock::new(0, 1).since_midnight(), Duration::from_secs(60));
+}
+
+#[test]
+fn test_add_and_subtract_durations() {
+    let mut clock = Clock::new(23, 30) + Duration::from_secs(45 * 60);
+    assert_eq!(clock, Clock::new(0, 15));
+    clock -= Duration::from_millis(1500);
+    assert_eq!(clock, Clock::from_hms_nano(0, 14, 58, 500_000_000));
+    // Whole days don't move the clock
+    assert_eq!(
+        Clock::new(6, 0) + Duration::from_secs(3 * 86_400 + 60),
+        Clock::new(6, 1)
+    );
+    assert_eq!(
+        Clock::new(6, 0) - SignedDuration::from_mins(-90),
+        Clock::new(7, 30)
+    );
+}
+
+#[test]
+fn test_difference_takes_the_short_way() {
+    assert_eq!(
+        Clock::new(1, 0) - Clock::new(23, 0),
+        SignedDuration::from_mins(120)
+    );
+    assert_eq!(
+        Clock::new(23, 0) - Clock::new(1, 0),
+        SignedDuration::from_mins(-120)
+    );
+    assert_eq!(
+        Clock::new(12, 0) - Clock::new(0, 0),
+        SignedDuration::from_mins(720)
+    );
+    let (a, b) = (Clock::from_hms(3, 4, 5), Clock::from_hms(17, 0, 1));
+    assert_eq!(b + (a - b), a);
+    assert_eq!(
+        (a - b).unsigned_abs(),
+        Duration::from_secs(10 * 3600 + 4 * 60 + 4)
+    );
+}
+
+#[test]
+fn test_clocks_sort_through_the_day() {
+    let mut clocks = vec![
+        Clock::new(25, 0),
+        Clock::new(0, -1),
+        Clock::from_hms(1, 0, -1),
+    ];
+    clocks.sort();
+    assert_eq!(
+        clocks,
+        vec![
+            Clock::from_hms(0, 59, 59),
+            Clock::new(1, 0),
+            Clock::new(23, 59)
+        ]
+    );
+}
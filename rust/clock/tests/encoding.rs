@@ -0,0 +1,78 @@
+use clock::Clock;
+
+#[test]
+fn test_bytes_round_trip() {
+    for clock in [
+        Clock::new(0, 0),
+        Clock::new(23, 59),
+        Clock::new(-1, -1),
+        Clock::new(-25, 160),
+        Clock::new(100, 1_000),
+        Clock::new(i32::MAX, i32::MIN),
+    ] {
+        assert_eq!(Clock::from_bytes(clock.to_bytes()), Some(clock));
+    }
+}
+
+#[test]
+fn test_bytes_hold_the_minutes() {
+    assert_eq!(Clock::new(8, 30).to_bytes(), [0x01, 0xFE]);
+    assert_eq!(Clock::from_hms(8, 30, 59).to_bytes(), [0x01, 0xFE]);
+    assert_eq!(Clock::from_bytes([0x05, 0x9F]), Some(Clock::new(23, 59)));
+    assert_eq!(Clock::from_bytes([0x05, 0xA0]), None);
+    assert_eq!(Clock::from_bytes([0xFF, 0xFF]), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    for clock in [
+        Clock::new(-1, -1),
+        Clock::new(-25, 160),
+        Clock::new(100, 1_000),
+        Clock::from_hms_nano(8, 30, 15, 250_000_000),
+    ] {
+        let json = serde_json::to_string(&clock).unwrap();
+        assert_eq!(serde_json::from_str::<Clock>(&json).unwrap(), clock);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_strings() {
+    assert_eq!(
+        serde_json::to_string(&Clock::new(-1, -1)).unwrap(),
+        r#""22:59""#
+    );
+    assert_eq!(
+        serde_json::to_string(&Clock::from_hms(8, 30, 15)).unwrap(),
+        r#""08:30:15""#
+    );
+    let clocks: Vec<Clock> = serde_json::from_str(r#"["8:30 pm", "0700"]"#).unwrap();
+    assert_eq!(clocks, vec![Clock::new(20, 30), Clock::new(7, 0)]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_minutes() {
+    let clocks: Vec<Clock> = serde_json::from_str("[90, -1, 1440, 100000]").unwrap();
+    assert_eq!(
+        clocks,
+        vec![
+            Clock::new(1, 30),
+            Clock::new(0, -1),
+            Clock::new(0, 1440),
+            Clock::new(0, 100_000)
+        ]
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_errors() {
+    let error = serde_json::from_str::<Clock>(r#""25:00""#).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("hour 25 is not between 0 and 23"));
+    assert!(serde_json::from_str::<Clock>("true").is_err());
+}
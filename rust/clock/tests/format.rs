@@ -0,0 +1,54 @@
+use clock::{Clock, ISO_8601, TWELVE_HOUR, TWELVE_HOUR_WITH_SECONDS, WITH_SECONDS};
+
+#[test]
+fn test_display_is_unchanged() {
+    assert_eq!(Clock::from_hms(20, 30, 59).to_string(), "20:30");
+}
+
+#[test]
+fn test_twelve_hour_clock() {
+    assert_eq!(
+        Clock::new(20, 30).format(TWELVE_HOUR).to_string(),
+        "08:30 PM"
+    );
+    assert_eq!(Clock::new(0, 5).format(TWELVE_HOUR).to_string(), "12:05 AM");
+    assert_eq!(
+        Clock::new(12, 0).format(TWELVE_HOUR).to_string(),
+        "12:00 PM"
+    );
+    assert_eq!(
+        Clock::from_hms(9, 1, 2)
+            .format(TWELVE_HOUR_WITH_SECONDS)
+            .to_string(),
+        "09:01:02 AM"
+    );
+}
+
+#[test]
+fn test_seconds_and_fractions() {
+    let clock = Clock::from_hms_nano(20, 30, 15, 250_000_000);
+    assert_eq!(clock.format(WITH_SECONDS).to_string(), "20:30:15");
+    assert_eq!(clock.format(ISO_8601).to_string(), "20:30:15.25");
+    assert_eq!(Clock::new(1, 2).format(ISO_8601).to_string(), "01:02:00");
+    assert_eq!(clock.format("%S.%3f").to_string(), "15.250");
+    assert_eq!(clock.format("%f").to_string(), "250000000");
+}
+
+#[test]
+fn test_custom_patterns() {
+    let clock = Clock::new(7, 5);
+    assert_eq!(clock.format("%Hh%M").to_string(), "07h05");
+    assert_eq!(clock.format("%I%P, 100%%").to_string(), "07am, 100%");
+    assert_eq!(clock.format("%R %q %").to_string(), "07:05 %q %");
+    assert_eq!(format!("[{:>7}]", clock.format("%R")), "[  07:05]");
+}
+
+#[test]
+fn test_formats_read_back() {
+    let clock = Clock::from_hms_nano(23, 4, 5, 6_000);
+    for pattern in [ISO_8601, TWELVE_HOUR_WITH_SECONDS] {
+        let text = clock.format(pattern).to_string();
+        let read = text.parse::<Clock>().unwrap();
+        assert_eq!(read.format(pattern).to_string(), text);
+    }
+}
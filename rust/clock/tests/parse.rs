@@ -0,0 +1,105 @@
+use clock::{Clock, Field, FixedOffset, ParseError};
+
+fn parse(text: &str) -> Result<Clock, ParseError> {
+    text.parse()
+}
+
+#[test]
+fn test_hours_and_minutes() {
+    assert_eq!(parse("08:30"), Ok(Clock::new(8, 30)));
+    assert_eq!(parse("8:30"), Ok(Clock::new(8, 30)));
+    assert_eq!(parse(" 23:59:01 "), Ok(Clock::from_hms(23, 59, 1)));
+    assert_eq!(
+        parse("00:00:07.25"),
+        Ok(Clock::from_hms_nano(0, 0, 7, 250_000_000))
+    );
+}
+
+#[test]
+fn test_twelve_hour_clock() {
+    assert_eq!(parse("8:30 pm"), Ok(Clock::new(20, 30)));
+    assert_eq!(parse("8 PM"), Ok(Clock::new(20, 0)));
+    assert_eq!(parse("12:05 a.m."), Ok(Clock::new(0, 5)));
+    assert_eq!(parse("12:05pm"), Ok(Clock::new(12, 5)));
+    assert_eq!(parse("11:59:59 AM"), Ok(Clock::from_hms(11, 59, 59)));
+}
+
+#[test]
+fn test_iso_8601() {
+    assert_eq!(parse("0830"), Ok(Clock::new(8, 30)));
+    assert_eq!(parse("083015"), Ok(Clock::from_hms(8, 30, 15)));
+    assert_eq!(parse("T08:30:15Z"), Ok(Clock::from_hms(8, 30, 15)));
+    assert_eq!(parse("24:00"), Ok(Clock::new(0, 0)));
+    assert_eq!(
+        Clock::parse_with_offset("08:30:15.5+05:30"),
+        Ok((
+            Clock::from_hms_nano(8, 30, 15, 500_000_000),
+            FixedOffset::from_hm(5, 30)
+        ))
+    );
+    assert_eq!(
+        Clock::parse_with_offset("2359-0800"),
+        Ok((Clock::new(23, 59), FixedOffset::from_hm(-8, 0)))
+    );
+    assert_eq!(
+        Clock::parse_with_offset("12:00"),
+        Ok((Clock::new(12, 0), None))
+    );
+}
+
+#[test]
+fn test_fields_out_of_range() {
+    let out_of_range = |field, value| Err(ParseError::OutOfRange { field, value });
+    assert_eq!(parse("25:00"), out_of_range(Field::Hour, 25));
+    assert_eq!(parse("24:01"), out_of_range(Field::Hour, 24));
+    assert_eq!(parse("08:60"), out_of_range(Field::Minute, 60));
+    assert_eq!(
+        parse("0830:61"),
+        Err(ParseError::BadFormat("0830:61".to_string()))
+    );
+    assert_eq!(parse("08:30:61"), out_of_range(Field::Second, 61));
+    assert_eq!(parse("13:00 pm"), out_of_range(Field::TwelveHour, 13));
+    assert_eq!(parse("0 am"), out_of_range(Field::TwelveHour, 0));
+}
+
+#[test]
+fn test_bad_formats() {
+    assert_eq!(parse("  "), Err(ParseError::Empty));
+    for text in ["8.30", "830", "08:3", "08:30:", "noon", "08:30.5", "1é2"] {
+        assert_eq!(parse(text), Err(ParseError::BadFormat(text.to_string())));
+    }
+    assert_eq!(
+        parse("08:30+25:00"),
+        Err(ParseError::BadOffset("+25:00".to_string()))
+    );
+}
+
+#[test]
+fn test_offsets() {
+    assert_eq!("Z".parse(), Ok(FixedOffset::UTC));
+    assert_eq!("-03:30".parse(), Ok(FixedOffset::from_hm(-3, 30).unwrap()));
+    assert_eq!("+0545".parse(), Ok(FixedOffset::from_hm(5, 45).unwrap()));
+    assert_eq!("+01".parse(), Ok(FixedOffset::from_hm(1, 0).unwrap()));
+    for text in ["05:30", "+5:30", "+05:60", "+1é2"] {
+        assert_eq!(
+            text.parse::<FixedOffset>(),
+            Err(ParseError::BadOffset(text.to_string()))
+        );
+    }
+}
+
+#[test]
+fn test_error_messages() {
+    assert_eq!(
+        parse("25:00").unwrap_err().to_string(),
+        "hour 25 is not between 0 and 23"
+    );
+    assert_eq!(
+        parse("0:15 pm").unwrap_err().to_string(),
+        "hour 0 is not between 1 and 12"
+    );
+    assert_eq!(
+        parse("later").unwrap_err().to_string(),
+        "`later` is not a time"
+    );
+}
@@ -0,0 +1,90 @@
+use clock::{Clock, Schedule, TimeRange, Weekday};
+
+fn range(text: &str) -> TimeRange {
+    text.parse().unwrap()
+}
+
+fn bar() -> Schedule {
+    use Weekday::*;
+    Schedule::new()
+        .with_days([Tuesday, Wednesday, Thursday], range("17:00-23:00"))
+        .with_days([Friday, Saturday], range("17:00-02:00"))
+        .with_range(Sunday, range("12:00-18:00"))
+}
+
+#[test]
+fn test_weekdays() {
+    assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+    assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+    assert_eq!(Weekday::ALL.len(), 7);
+    assert_eq!(Weekday::Wednesday.to_string(), "Wednesday");
+}
+
+#[test]
+fn test_open_past_midnight() {
+    let bar = bar();
+    assert!(bar.is_open(Weekday::Friday, Clock::new(22, 0)));
+    assert!(bar.is_open(Weekday::Saturday, Clock::new(1, 30)));
+    assert!(bar.is_open(Weekday::Sunday, Clock::new(1, 59)));
+    assert!(!bar.is_open(Weekday::Sunday, Clock::new(2, 0)));
+    assert!(!bar.is_open(Weekday::Monday, Clock::new(20, 0)));
+}
+
+#[test]
+fn test_next_opening() {
+    let bar = bar();
+    assert_eq!(
+        bar.next_opening(Weekday::Monday, Clock::new(9, 0)),
+        Some((Weekday::Tuesday, Clock::new(17, 0)))
+    );
+    assert_eq!(
+        bar.next_opening(Weekday::Tuesday, Clock::new(17, 0)),
+        Some((Weekday::Tuesday, Clock::new(17, 0)))
+    );
+    // Already open, so the next opening is the next evening
+    assert_eq!(
+        bar.next_opening(Weekday::Saturday, Clock::new(1, 0)),
+        Some((Weekday::Saturday, Clock::new(17, 0)))
+    );
+    // Round the end of the week
+    assert_eq!(
+        bar.next_opening(Weekday::Sunday, Clock::new(19, 0)),
+        Some((Weekday::Tuesday, Clock::new(17, 0)))
+    );
+}
+
+#[test]
+fn test_next_closing() {
+    assert_eq!(
+        bar().next_closing(Weekday::Friday, Clock::new(18, 0)),
+        Some((Weekday::Saturday, Clock::new(2, 0)))
+    );
+}
+
+#[test]
+fn test_ranges_which_meet_are_joined() {
+    let schedule = Schedule::new()
+        .with_range(Weekday::Sunday, range("20:00-00:00"))
+        .with_range(Weekday::Monday, range("00:00-04:00"))
+        .with_range(Weekday::Monday, range("02:00-06:00"));
+    assert_eq!(
+        schedule.next_opening(Weekday::Monday, Clock::new(1, 0)),
+        Some((Weekday::Sunday, Clock::new(20, 0)))
+    );
+    assert_eq!(
+        schedule.next_closing(Weekday::Sunday, Clock::new(21, 0)),
+        Some((Weekday::Monday, Clock::new(6, 0)))
+    );
+}
+
+#[test]
+fn test_never_open_or_never_closed() {
+    assert_eq!(
+        Schedule::new().next_opening(Weekday::Monday, Clock::new(0, 0)),
+        None
+    );
+    let always = Schedule::new().with_days(Weekday::ALL, TimeRange::whole_day());
+    assert!(always.is_open(Weekday::Thursday, Clock::new(3, 0)));
+    assert_eq!(always.next_opening(Weekday::Monday, Clock::new(0, 0)), None);
+    assert_eq!(always.next_closing(Weekday::Monday, Clock::new(0, 0)), None);
+}
@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use clock::{Clock, TimeRange};
+
+fn range(text: &str) -> TimeRange {
+    text.parse().unwrap()
+}
+
+#[test]
+fn test_contains_across_midnight() {
+    let night = range("22:00-02:00");
+    assert!(night.wraps_midnight());
+    assert!(night.contains(Clock::new(23, 0)));
+    assert!(night.contains(Clock::new(0, 0)));
+    assert!(night.contains(Clock::from_hms(1, 59, 59)));
+    assert!(!night.contains(Clock::new(2, 0)));
+    assert!(!night.contains(Clock::new(12, 0)));
+    assert_eq!(night.duration(), Duration::from_secs(4 * 3600));
+}
+
+#[test]
+fn test_same_start_and_end_is_the_whole_day() {
+    let day = TimeRange::new(Clock::new(8, 0), Clock::new(32, 0));
+    assert!(day.is_whole_day());
+    assert_eq!(day, TimeRange::whole_day());
+    assert!(day.contains(Clock::new(7, 59)));
+    assert_eq!(day.to_string(), "00:00-00:00");
+}
+
+#[test]
+fn test_overlaps() {
+    assert!(range("22:00-02:00").overlaps(&range("01:00-03:00")));
+    assert!(range("01:00-03:00").overlaps(&range("22:00-02:00")));
+    assert!(!range("22:00-02:00").overlaps(&range("02:00-22:00")));
+    assert!(!range("09:00-12:00").overlaps(&range("12:00-13:00")));
+    assert!(range("09:00-12:00").overlaps(&TimeRange::whole_day()));
+}
+
+#[test]
+fn test_intersection() {
+    assert_eq!(
+        range("22:00-02:00").intersection(&range("23:30-08:00")),
+        vec![range("23:30-02:00")]
+    );
+    let mut both = range("20:00-04:00").intersection(&range("02:00-22:00"));
+    both.sort_by_key(TimeRange::start);
+    assert_eq!(both, vec![range("02:00-04:00"), range("20:00-22:00")]);
+    assert_eq!(
+        range("08:00-09:00").intersection(&range("10:00-11:00")),
+        vec![]
+    );
+}
+
+#[test]
+fn test_union() {
+    assert_eq!(
+        range("22:00-02:00").union(&range("01:00-03:00")),
+        Some(range("22:00-03:00"))
+    );
+    assert_eq!(
+        range("09:00-12:00").union(&range("12:00-13:00")),
+        Some(range("09:00-13:00"))
+    );
+    assert_eq!(
+        range("20:00-04:00").union(&range("02:00-22:00")),
+        Some(TimeRange::whole_day())
+    );
+    assert_eq!(range("08:00-09:00").union(&range("10:00-11:00")), None);
+}
+
+#[test]
+fn test_parse_and_display() {
+    assert_eq!(range("10 pm – 2 am"), range("22:00-02:00"));
+    assert_eq!(range("2200-0200").to_string(), "22:00-02:00");
+    assert!("22:00".parse::<TimeRange>().is_err());
+    assert!("22:00-26:00".parse::<TimeRange>().is_err());
+}
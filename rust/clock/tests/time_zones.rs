@@ -0,0 +1,32 @@
+use clock::{Clock, FixedOffset};
+
+#[test]
+fn test_offsets() {
+    assert_eq!(FixedOffset::from_hm(5, 30).unwrap().seconds(), 19_800);
+    assert_eq!(FixedOffset::from_hm(-3, 30).unwrap().to_string(), "-03:30");
+    assert_eq!(FixedOffset::east(3_661).unwrap().to_string(), "+01:01:01");
+    assert_eq!(FixedOffset::UTC.to_string(), "+00:00");
+    assert_eq!(FixedOffset::west(3600), FixedOffset::from_hm(-1, 0));
+    assert_eq!(FixedOffset::from_hm(0, -30).unwrap().to_string(), "-00:30");
+    assert_eq!(FixedOffset::from_hm(0, -30), "-00:30".parse().ok());
+}
+
+#[test]
+fn test_offsets_out_of_range() {
+    assert_eq!(FixedOffset::east(86_400), None);
+    assert_eq!(FixedOffset::west(86_400), None);
+    assert_eq!(FixedOffset::from_hm(2, 60), None);
+    assert_eq!(FixedOffset::from_hm(2, -30), None);
+    assert_eq!(FixedOffset::from_hm(0, -60), None);
+    assert_eq!(FixedOffset::from_hm(i32::MIN, 0), None);
+}
+
+#[test]
+fn test_conversion_wraps_the_day() {
+    let tokyo = FixedOffset::from_hm(9, 0).unwrap();
+    let new_york = FixedOffset::from_hm(-5, 0).unwrap();
+    assert_eq!(Clock::new(8, 0).to_utc(tokyo), Clock::new(23, 0));
+    assert_eq!(Clock::new(23, 0).in_offset(tokyo), Clock::new(8, 0));
+    assert_eq!(Clock::new(8, 0).convert(tokyo, new_york), Clock::new(18, 0));
+    assert_eq!(Clock::new(18, 0).convert(new_york, tokyo), Clock::new(8, 0));
+}
@@ -0,0 +1,162 @@
+// Adding amounts of time to dates. Seconds up to weeks have a fixed length and are added as a
+// duration, months and years move the date on the calendar, keeping the time of day and taking
+// the last day of the month when the day is past it, so Jan 31 plus a month is Feb 28 or 29.
+
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+/// A point in time `after_units` can add to, with or without an offset from UTC.
+pub trait Moment: Copy + Ord {
+    fn date(self) -> Date;
+
+    fn time(self) -> Time;
+
+    /// The moment in UTC, `None` without an offset to tell it.
+    fn to_utc(self) -> Option<OffsetDateTime>;
+
+    fn with_date(self, date: Date) -> Self;
+
+    fn checked_add(self, duration: Duration) -> Option<Self>;
+
+    /// The time from `earlier` to this, negative if `earlier` is later.
+    fn since(self, earlier: Self) -> Duration;
+}
+
+impl Moment for PrimitiveDateTime {
+    fn date(self) -> Date {
+        PrimitiveDateTime::date(self)
+    }
+
+    fn time(self) -> Time {
+        PrimitiveDateTime::time(self)
+    }
+
+    fn to_utc(self) -> Option<OffsetDateTime> {
+        None
+    }
+
+    fn with_date(self, date: Date) -> Self {
+        self.replace_date(date)
+    }
+
+    fn checked_add(self, duration: Duration) -> Option<Self> {
+        PrimitiveDateTime::checked_add(self, duration)
+    }
+
+    fn since(self, earlier: Self) -> Duration {
+        self - earlier
+    }
+}
+
+/// Calendar units go by the date where the offset is, fixed units by the time in UTC.
+impl Moment for OffsetDateTime {
+    fn date(self) -> Date {
+        OffsetDateTime::date(self)
+    }
+
+    fn time(self) -> Time {
+        OffsetDateTime::time(self)
+    }
+
+    fn to_utc(self) -> Option<OffsetDateTime> {
+        Some(self.to_offset(UtcOffset::UTC))
+    }
+
+    fn with_date(self, date: Date) -> Self {
+        self.replace_date(date)
+    }
+
+    fn checked_add(self, duration: Duration) -> Option<Self> {
+        OffsetDateTime::checked_add(self, duration)
+    }
+
+    fn since(self, earlier: Self) -> Duration {
+        self - earlier
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Unit {
+    /// The seconds in the unit, `None` for months and years which vary in length.
+    pub fn seconds(self) -> Option<i64> {
+        match self {
+            Unit::Seconds => Some(1),
+            Unit::Minutes => Some(60),
+            Unit::Hours => Some(3_600),
+            Unit::Days => Some(86_400),
+            Unit::Weeks => Some(604_800),
+            Unit::Months | Unit::Years => None,
+        }
+    }
+
+    // The months in a calendar unit
+    fn months(self) -> Option<i64> {
+        match self {
+            Unit::Months => Some(1),
+            Unit::Years => Some(12),
+            _ => None,
+        }
+    }
+}
+
+/// `amount` of `unit` after `start`, or before it for a negative amount. `None` when the
+/// result is outside the dates `time` can hold.
+pub fn after_units<T: Moment>(start: T, amount: i64, unit: Unit) -> Option<T> {
+    match (unit.seconds(), unit.months()) {
+        (Some(seconds), _) => start.checked_add(Duration::seconds(amount.checked_mul(seconds)?)),
+        (_, Some(months)) => add_months(start, amount.checked_mul(months)?),
+        _ => None,
+    }
+}
+
+/// The first time after `moment` which is a whole number of `amount` `unit`s after `start`,
+/// such as the next gigasecond anniversary with 1_000_000_000 seconds. It's never `start` itself.
+pub fn next_anniversary<T: Moment>(start: T, amount: i64, unit: Unit, moment: T) -> Option<T> {
+    if amount <= 0 {
+        return None;
+    }
+
+    let mut times = match (unit.seconds(), unit.months()) {
+        (Some(seconds), _) => {
+            let period = amount as i128 * seconds as i128 * 1_000_000_000;
+            let passed = moment.since(start).whole_nanoseconds().div_euclid(period) + 1;
+            i64::try_from(passed).ok()?
+        }
+        // Anniversaries in months before the one of `moment` are before it
+        (_, Some(months)) => (month_index(moment.date()) - month_index(start.date()))
+            .div_euclid(amount.checked_mul(months)?),
+        _ => return None,
+    }
+    .max(1);
+
+    loop {
+        let anniversary = after_units(start, times.checked_mul(amount)?, unit)?;
+        if anniversary > moment {
+            return Some(anniversary);
+        }
+        times += 1;
+    }
+}
+
+// Months since the start of year 0
+fn month_index(date: Date) -> i64 {
+    date.year() as i64 * 12 + date.month() as i64 - 1
+}
+
+fn add_months<T: Moment>(start: T, months: i64) -> Option<T> {
+    let date = start.date();
+    let index = month_index(date).checked_add(months)?;
+    let year = i32::try_from(index.div_euclid(12)).ok()?;
+    let month = Month::try_from(index.rem_euclid(12) as u8 + 1).ok()?;
+    let day = date.day().min(month.length(year));
+    Some(start.with_date(Date::from_calendar_date(year, month, day).ok()?))
+}
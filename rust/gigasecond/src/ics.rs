@@ -0,0 +1,97 @@
+// iCalendar files as RFC 5545 describes them: lines end in CRLF and longer than 75 bytes are
+// folded onto lines starting with a space, text escapes `\`, `;`, `,` and newlines.
+
+use time::{Date, OffsetDateTime, Time};
+
+use crate::{Milestone, Moment};
+
+pub(crate) fn write<T: Moment>(
+    name: &str,
+    milestones: &[Milestone<T>],
+    created: OffsetDateTime,
+) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//gigasecond//milestones//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape(&format!("{name}'s milestones"))),
+    ];
+
+    let stamp = utc_time(created);
+    let person = slug(name);
+    for milestone in milestones {
+        let at = match milestone.at().to_utc() {
+            Some(utc) => utc_time(utc),
+            None => local_time(milestone.at().date(), milestone.at().time()),
+        };
+        let summary = format!("{name} is {milestone} old");
+        let uid: String = format!("{at}-{milestone}")
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect();
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{uid}-{person}@gigasecond"),
+            format!("DTSTAMP:{stamp}"),
+            format!("DTSTART:{at}"),
+            format!("SUMMARY:{}", escape(&summary)),
+            "END:VEVENT".to_string(),
+        ]);
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+fn utc_time(utc: OffsetDateTime) -> String {
+    local_time(utc.date(), utc.time()) + "Z"
+}
+
+fn local_time(date: Date, time: Time) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}",
+        date.year(),
+        date.month() as u8,
+        date.day(),
+        time.hour(),
+        time.minute(),
+        time.second()
+    )
+}
+
+// CR and CRLF are taken as newlines too, a bare CR would end the line early in some readers
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .replace('\n', "\\n")
+}
+
+// The name with every byte but ASCII letters and digits written as `%` and two hex digits, so
+// different names never give the same UID
+fn slug(name: &str) -> String {
+    name.bytes()
+        .map(|byte| match byte.is_ascii_alphanumeric() {
+            true => (byte as char).to_string(),
+            false => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+// Breaks a line into pieces of at most 75 bytes without splitting a character
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded
+}
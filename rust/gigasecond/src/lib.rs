@@ -1,5 +1,12 @@
 use time::{ext::NumericalDuration, PrimitiveDateTime as DateTime};
 
+mod calendar;
+mod ics;
+mod milestone;
+
+pub use calendar::{after_units, next_anniversary, Moment, Unit};
+pub use milestone::{Measure, Milestone, MilestoneCalendar};
+
 // Returns a DateTime one billion seconds after start.
 pub fn after(start: DateTime) -> DateTime {
     start + (1000000000).seconds()
@@ -0,0 +1,155 @@
+// Round number ages: 10^n of a measure after a birth, from 1 up. Every second is as long as
+// the next, there are no leap seconds, so 10^9 seconds after 2011-04-25 is 2043-01-01 01:46:40
+// whatever happens to the clocks of the world in between.
+
+use std::fmt;
+
+use space_age::{Mars, Planet};
+use time::{Duration, OffsetDateTime};
+
+use crate::{after_units, ics, Moment, Unit};
+
+/// What milestones count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Measure {
+    Unit(Unit),
+    /// Years of another planet, `seconds` Earth seconds long.
+    PlanetYears {
+        planet: &'static str,
+        seconds: f64,
+    },
+}
+
+impl Measure {
+    /// Years of a planet from `space_age`.
+    pub fn planet_years<P: Planet>(planet: &'static str) -> Measure {
+        Measure::PlanetYears {
+            planet,
+            seconds: P::RELATIVE_EARTH_YEARS,
+        }
+    }
+
+    /// Seconds, minutes, hours, days and Mars years.
+    pub fn defaults() -> Vec<Measure> {
+        vec![
+            Measure::Unit(Unit::Seconds),
+            Measure::Unit(Unit::Minutes),
+            Measure::Unit(Unit::Hours),
+            Measure::Unit(Unit::Days),
+            Measure::planet_years::<Mars>("Mars"),
+        ]
+    }
+
+    // `count` of the measure after `start`, planet years to the nearest microsecond
+    fn after<T: Moment>(&self, start: T, count: i64) -> Option<T> {
+        match *self {
+            Measure::Unit(unit) => after_units(start, count, unit),
+            Measure::PlanetYears { seconds, .. } => {
+                let micros = (seconds * count as f64 * 1e6).round();
+                let micros = (micros.abs() < i64::MAX as f64).then_some(micros as i64)?;
+                start.checked_add(Duration::microseconds(micros))
+            }
+        }
+    }
+
+    fn name(&self, count: i64) -> String {
+        let (name, plural) = match self {
+            Measure::Unit(unit) => {
+                let name = format!("{unit:?}").to_lowercase();
+                (name.trim_end_matches('s').to_string(), name)
+            }
+            Measure::PlanetYears { planet, .. } => {
+                (format!("{planet} year"), format!("{planet} years"))
+            }
+        };
+        match count {
+            1 => name,
+            _ => plural,
+        }
+    }
+}
+
+/// A round number of a measure after a birth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Milestone<T> {
+    at: T,
+    count: i64,
+    measure: Measure,
+}
+
+impl<T: Copy> Milestone<T> {
+    pub fn at(&self) -> T {
+        self.at
+    }
+
+    /// A power of 10.
+    pub fn count(&self) -> i64 {
+        self.count
+    }
+
+    pub fn measure(&self) -> Measure {
+        self.measure
+    }
+}
+
+/// `1,000,000,000 seconds`
+impl<T> fmt::Display for Milestone<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.count.to_string();
+        let mut count = String::new();
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                count.push(',');
+            }
+            count.push(digit);
+        }
+        f.pad(&format!("{count} {}", self.measure.name(self.count)))
+    }
+}
+
+/// The milestones of someone born at a moment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MilestoneCalendar<T> {
+    name: String,
+    birth: T,
+    measures: Vec<Measure>,
+}
+
+impl<T: Moment> MilestoneCalendar<T> {
+    /// A calendar of the default measures for `name`, who appears in the events.
+    pub fn new(name: &str, birth: T) -> Self {
+        Self {
+            name: name.to_string(),
+            birth,
+            measures: Measure::defaults(),
+        }
+    }
+
+    pub fn with_measures(self, measures: Vec<Measure>) -> Self {
+        Self { measures, ..self }
+    }
+
+    /// Every milestone from `from` up to `to`, both included, in order.
+    pub fn between(&self, from: T, to: T) -> Vec<Milestone<T>> {
+        let mut milestones = vec![];
+        for &measure in &self.measures {
+            let counts = (0..19).map(|n| 10i64.pow(n));
+            for count in counts {
+                let Some(at) = measure.after(self.birth, count).filter(|&at| at <= to) else {
+                    break;
+                };
+                if at >= from {
+                    milestones.push(Milestone { at, count, measure });
+                }
+            }
+        }
+        milestones.sort_by_key(|milestone| milestone.at);
+        milestones
+    }
+
+    /// The milestones from `from` up to `to` as an iCalendar file, with `created` as the time
+    /// the events were made. Moments without an offset are written as local times.
+    pub fn to_ics(&self, from: T, to: T, created: OffsetDateTime) -> String {
+        ics::write(&self.name, &self.between(from, to), created)
+    }
+}
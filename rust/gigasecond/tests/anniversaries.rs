@@ -0,0 +1,68 @@
+use gigasecond::{after, next_anniversary, Unit};
+use time::PrimitiveDateTime as DateTime;
+
+fn dt(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+    use time::{Date, Time};
+
+    DateTime::new(
+        Date::from_calendar_date(year, month.try_into().unwrap(), day).unwrap(),
+        Time::from_hms(hour, minute, second).unwrap(),
+    )
+}
+
+#[test]
+fn test_next_gigasecond() {
+    let birth = dt(1977, 6, 13, 0, 0, 0);
+    let first = after(birth);
+    assert_eq!(
+        next_anniversary(birth, 1_000_000_000, Unit::Seconds, dt(2000, 1, 1, 0, 0, 0)),
+        Some(first)
+    );
+    // Strictly after, so on the day itself it's the second one
+    assert_eq!(
+        next_anniversary(birth, 1_000_000_000, Unit::Seconds, first),
+        Some(after(first))
+    );
+    // Never the start itself
+    assert_eq!(
+        next_anniversary(birth, 1_000_000_000, Unit::Seconds, dt(1900, 1, 1, 0, 0, 0)),
+        Some(first)
+    );
+}
+
+#[test]
+fn test_next_megaminute() {
+    let birth = dt(2011, 4, 25, 0, 0, 0);
+    assert_eq!(
+        next_anniversary(birth, 1_000_000, Unit::Minutes, dt(2014, 1, 1, 0, 0, 0)),
+        Some(dt(2015, 2, 11, 21, 20, 0))
+    );
+}
+
+#[test]
+fn test_next_calendar_anniversary() {
+    let birth = dt(2020, 2, 29, 9, 0, 0);
+    assert_eq!(
+        next_anniversary(birth, 1, Unit::Years, dt(2023, 2, 28, 8, 0, 0)),
+        Some(dt(2023, 2, 28, 9, 0, 0))
+    );
+    assert_eq!(
+        next_anniversary(birth, 1, Unit::Years, dt(2023, 2, 28, 9, 0, 0)),
+        Some(dt(2024, 2, 29, 9, 0, 0))
+    );
+    assert_eq!(
+        next_anniversary(birth, 10, Unit::Years, dt(2023, 2, 28, 9, 0, 0)),
+        Some(dt(2030, 2, 28, 9, 0, 0))
+    );
+    assert_eq!(
+        next_anniversary(birth, 100, Unit::Months, dt(2025, 1, 1, 0, 0, 0)),
+        Some(dt(2028, 6, 29, 9, 0, 0))
+    );
+}
+
+#[test]
+fn test_amounts_must_be_positive() {
+    let birth = dt(2020, 2, 29, 9, 0, 0);
+    assert_eq!(next_anniversary(birth, 0, Unit::Days, birth), None);
+    assert_eq!(next_anniversary(birth, -1, Unit::Years, birth), None);
+}
@@ -0,0 +1,97 @@
+use gigasecond::{after, after_units, Unit};
+use time::{PrimitiveDateTime as DateTime, UtcOffset};
+
+fn dt(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+    use time::{Date, Time};
+
+    DateTime::new(
+        Date::from_calendar_date(year, month.try_into().unwrap(), day).unwrap(),
+        Time::from_hms(hour, minute, second).unwrap(),
+    )
+}
+
+#[test]
+fn test_fixed_units() {
+    let start = dt(2011, 4, 25, 0, 0, 0);
+    assert_eq!(
+        after_units(start, 1_000_000_000, Unit::Seconds),
+        Some(after(start))
+    );
+    assert_eq!(
+        after_units(start, 90, Unit::Minutes),
+        Some(dt(2011, 4, 25, 1, 30, 0))
+    );
+    assert_eq!(
+        after_units(start, -2, Unit::Weeks),
+        Some(dt(2011, 4, 11, 0, 0, 0))
+    );
+    assert_eq!(
+        after_units(start, 1_000_000, Unit::Minutes),
+        Some(dt(2013, 3, 19, 10, 40, 0))
+    );
+}
+
+#[test]
+fn test_months_clamp_to_the_end_of_the_month() {
+    let start = dt(2023, 1, 31, 12, 0, 0);
+    assert_eq!(
+        after_units(start, 1, Unit::Months),
+        Some(dt(2023, 2, 28, 12, 0, 0))
+    );
+    assert_eq!(
+        after_units(start, 13, Unit::Months),
+        Some(dt(2024, 2, 29, 12, 0, 0))
+    );
+    assert_eq!(
+        after_units(start, 3, Unit::Months),
+        Some(dt(2023, 4, 30, 12, 0, 0))
+    );
+    assert_eq!(
+        after_units(start, -2, Unit::Months),
+        Some(dt(2022, 11, 30, 12, 0, 0))
+    );
+}
+
+#[test]
+fn test_years_from_a_leap_day() {
+    let start = dt(2020, 2, 29, 0, 0, 0);
+    assert_eq!(
+        after_units(start, 1, Unit::Years),
+        Some(dt(2021, 2, 28, 0, 0, 0))
+    );
+    assert_eq!(
+        after_units(start, 4, Unit::Years),
+        Some(dt(2024, 2, 29, 0, 0, 0))
+    );
+    assert_eq!(
+        after_units(start, -100, Unit::Years),
+        Some(dt(1920, 2, 29, 0, 0, 0))
+    );
+}
+
+#[test]
+fn test_offset_date_times() {
+    let offset = UtcOffset::from_hms(5, 30, 0).unwrap();
+    let start = dt(2011, 4, 25, 0, 0, 0).assume_offset(offset);
+    let later = after_units(start, 1_000_000_000, Unit::Seconds).unwrap();
+    assert_eq!(later.offset(), offset);
+    assert_eq!(later, dt(2043, 1, 1, 1, 46, 40).assume_offset(offset));
+    assert_eq!(
+        after_units(start, 1, Unit::Months),
+        Some(dt(2011, 5, 25, 0, 0, 0).assume_offset(offset))
+    );
+    // The same moment in UTC is a day earlier on the calendar
+    let utc = start.to_offset(UtcOffset::UTC);
+    assert_eq!(
+        after_units(utc, 1, Unit::Months),
+        Some(dt(2011, 5, 24, 18, 30, 0).assume_utc())
+    );
+}
+
+#[test]
+fn test_out_of_range() {
+    let start = dt(2011, 4, 25, 0, 0, 0);
+    assert_eq!(after_units(start, 100_000, Unit::Years), None);
+    assert_eq!(after_units(start, i64::MAX, Unit::Seconds), None);
+    assert_eq!(after_units(start, i64::MIN, Unit::Years), None);
+}
@@ -0,0 +1,145 @@
+use gigasecond::{Measure, MilestoneCalendar, Unit};
+use space_age::Jupiter;
+use time::{PrimitiveDateTime as DateTime, UtcOffset};
+
+fn dt(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+    use time::{Date, Time};
+
+    DateTime::new(
+        Date::from_calendar_date(year, month.try_into().unwrap(), day).unwrap(),
+        Time::from_hms(hour, minute, second).unwrap(),
+    )
+}
+
+#[test]
+fn test_milestones_in_order() {
+    let calendar = MilestoneCalendar::new("Ada", dt(2011, 4, 25, 0, 0, 0));
+    let milestones = calendar.between(dt(2020, 1, 1, 0, 0, 0), dt(2045, 1, 1, 0, 0, 0));
+    let found = milestones
+        .iter()
+        .map(|milestone| (milestone.to_string(), milestone.at()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        vec![
+            ("100,000 hours".to_string(), dt(2022, 9, 20, 16, 0, 0)),
+            (
+                "10 Mars years".to_string(),
+                dt(2030, 2, 13, 16, 18, 46) + time::Duration::nanoseconds(900_800_000)
+            ),
+            ("10,000,000 minutes".to_string(), dt(2030, 4, 29, 10, 40, 0)),
+            ("10,000 days".to_string(), dt(2038, 9, 10, 0, 0, 0)),
+            (
+                "1,000,000,000 seconds".to_string(),
+                dt(2043, 1, 1, 1, 46, 40)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_known_values_without_leap_seconds() {
+    // 2016-12-31 had a leap second, which doesn't count
+    let calendar = MilestoneCalendar::new("Ada", dt(2015, 1, 1, 0, 0, 0))
+        .with_measures(vec![Measure::Unit(Unit::Seconds)]);
+    let milestones = calendar.between(dt(2015, 1, 1, 0, 0, 0), dt(2020, 1, 1, 0, 0, 0));
+    let ats = milestones.iter().map(|m| m.at()).collect::<Vec<_>>();
+    assert_eq!(ats.first(), Some(&dt(2015, 1, 1, 0, 0, 1)));
+    assert_eq!(ats.last(), Some(&dt(2018, 3, 3, 9, 46, 40)));
+    assert_eq!(milestones.last().unwrap().count(), 100_000_000);
+    assert_eq!(ats.len(), 9);
+}
+
+#[test]
+fn test_other_planets() {
+    let calendar = MilestoneCalendar::new("Ada", dt(2000, 1, 1, 0, 0, 0))
+        .with_measures(vec![Measure::planet_years::<Jupiter>("Jupiter")]);
+    let milestones = calendar.between(dt(2000, 1, 1, 0, 0, 0), dt(2100, 1, 1, 0, 0, 0));
+    assert_eq!(milestones.len(), 1);
+    assert_eq!(milestones[0].to_string(), "1 Jupiter year");
+    assert_eq!(milestones[0].at().date(), dt(2011, 11, 11, 0, 0, 0).date());
+}
+
+#[test]
+fn test_ics_export() {
+    let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+    let calendar = MilestoneCalendar::new("Ada", dt(2011, 4, 25, 0, 0, 0).assume_offset(offset))
+        .with_measures(vec![Measure::Unit(Unit::Seconds)]);
+    let ics = calendar.to_ics(
+        dt(2040, 1, 1, 0, 0, 0).assume_utc(),
+        dt(2050, 1, 1, 0, 0, 0).assume_utc(),
+        dt(2024, 5, 6, 7, 8, 9).assume_utc(),
+    );
+    assert_eq!(
+        ics,
+        [
+            "BEGIN:VCALENDAR",
+            "VERSION:2.0",
+            "PRODID:-//gigasecond//milestones//EN",
+            "CALSCALE:GREGORIAN",
+            "X-WR-CALNAME:Ada's milestones",
+            "BEGIN:VEVENT",
+            "UID:20421231T234640Z1000000000seconds-Ada@gigasecond",
+            "DTSTAMP:20240506T070809Z",
+            "DTSTART:20421231T234640Z",
+            "SUMMARY:Ada is 1\\,000\\,000\\,000 seconds old",
+            "END:VEVENT",
+            "END:VCALENDAR",
+            "",
+        ]
+        .join("\r\n")
+    );
+}
+
+#[test]
+fn test_ics_local_times_and_long_lines() {
+    let name = "Augusta Ada King, Countess of Lovelace; née Byron";
+    let calendar = MilestoneCalendar::new(name, dt(2011, 4, 25, 0, 0, 0))
+        .with_measures(vec![Measure::Unit(Unit::Days)]);
+    let ics = calendar.to_ics(
+        dt(2038, 1, 1, 0, 0, 0),
+        dt(2039, 1, 1, 0, 0, 0),
+        dt(2024, 5, 6, 7, 8, 9).assume_utc(),
+    );
+    assert!(ics.contains("\r\nDTSTART:20380910T000000\r\n"));
+    assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+    assert!(ics.replace("\r\n ", "").contains(
+        r"SUMMARY:Augusta Ada King\, Countess of Lovelace\; née Byron is 10\,000 days old"
+    ));
+}
+
+#[test]
+fn test_ics_uids_differ_between_people() {
+    let uids = |name: &str| {
+        MilestoneCalendar::new(name, dt(2011, 4, 25, 0, 0, 0))
+            .with_measures(vec![Measure::Unit(Unit::Seconds)])
+            .to_ics(
+                dt(2040, 1, 1, 0, 0, 0),
+                dt(2050, 1, 1, 0, 0, 0),
+                dt(2024, 5, 6, 7, 8, 9).assume_utc(),
+            )
+            .lines()
+            .filter(|line| line.starts_with("UID:"))
+            .map(str::to_string)
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(
+        uids("Ada Byron"),
+        ["UID:20430101T0146401000000000seconds-Ada%20Byron@gigasecond"]
+    );
+    assert_ne!(uids("Ada"), uids("Ava"));
+    assert_ne!(uids("Zoë"), uids("Zoé"));
+}
+
+#[test]
+fn test_ics_escapes_carriage_returns() {
+    let calendar = MilestoneCalendar::new("Ada\rLovelace", dt(2011, 4, 25, 0, 0, 0))
+        .with_measures(vec![Measure::Unit(Unit::Seconds)]);
+    let ics = calendar.to_ics(
+        dt(2040, 1, 1, 0, 0, 0),
+        dt(2050, 1, 1, 0, 0, 0),
+        dt(2024, 5, 6, 7, 8, 9).assume_utc(),
+    );
+    assert!(!ics.replace("\r\n", "").contains('\r'));
+    assert!(ics.contains(r"SUMMARY:Ada\nLovelace is 1\,000\,000\,000 seconds old"));
+}
@@ -0,0 +1,79 @@
+// Compares ranking every five card hand with `Hand::rank` against the lookup tables,
+// run with `cargo bench`.
+//
+// rank            2598960 hands in 1.456196946s (560 ns/hand)
+// evaluate5       2598960 hands in 71.453149ms (27 ns/hand)
+// evaluate 7      133784560 hands in 11.526461676s (86 ns/hand)
+
+use std::time::Instant;
+
+use poker::{evaluate, evaluate5, Card, CardTypes, Hand, PackedCard};
+
+fn deck() -> Vec<Card> {
+    [
+        CardTypes::Spades,
+        CardTypes::Hearts,
+        CardTypes::Diamonds,
+        CardTypes::Clovers,
+    ]
+    .iter()
+    .flat_map(|&card_type| (2..=14).map(move |value| Card::new(value, card_type).unwrap()))
+    .collect()
+}
+
+// Calls `f` with every combination of `k` out of `n` indices
+fn for_each_combination(n: usize, k: usize, f: &mut impl FnMut(&[usize])) {
+    let mut indices = (0..k).collect::<Vec<usize>>();
+    loop {
+        f(&indices);
+        match (0..k).rev().find(|&i| indices[i] < n - k + i) {
+            Some(i) => {
+                indices[i] += 1;
+                (i + 1..k).for_each(|j| indices[j] = indices[j - 1] + 1);
+            }
+            None => return,
+        }
+    }
+}
+
+fn bench(name: &str, hands: usize, f: impl FnOnce() -> u64) {
+    let start = Instant::now();
+    let checksum = f();
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<15} {hands} hands in {elapsed:?} ({} ns/hand) [{checksum}]",
+        elapsed.as_nanos() / hands as u128
+    );
+}
+
+fn main() {
+    let deck = deck();
+    let packed = deck.iter().map(PackedCard::from).collect::<Vec<_>>();
+
+    bench("rank", 2_598_960, || {
+        let mut sum = 0;
+        for_each_combination(52, 5, &mut |i| {
+            let cards = i.iter().map(|&i| deck[i].clone()).collect::<Vec<_>>();
+            sum += Hand::from_cards(&cards).unwrap().rank().kickers()[0] as u64;
+        });
+        sum
+    });
+
+    bench("evaluate5", 2_598_960, || {
+        let mut sum = 0;
+        for_each_combination(52, 5, &mut |i| {
+            sum += evaluate5([0, 1, 2, 3, 4].map(|j| packed[i[j]])).value() as u64;
+        });
+        sum
+    });
+
+    bench("evaluate 7", 133_784_560, || {
+        let mut sum = 0;
+        let mut cards = [packed[0]; 7];
+        for_each_combination(52, 7, &mut |i| {
+            (0..7).for_each(|j| cards[j] = packed[i[j]]);
+            sum += evaluate(&cards).value() as u64;
+        });
+        sum
+    });
+}
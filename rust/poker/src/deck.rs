@@ -0,0 +1,182 @@
+use std::fmt;
+
+use crate::{rng::Rng, Card, CardTypes};
+
+/// Something that happened to a deck, in the order it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckEvent {
+    Shuffle(u64),
+    Deal(Vec<Card>),
+    Burn(Card),
+    Remove(Vec<Card>),
+}
+
+/// Errors from taking cards out of a deck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckError {
+    NotEnoughCards {
+        wanted: usize,
+        left: usize,
+    },
+    CardNotInDeck(Card),
+    ReplayMismatch {
+        expected: Vec<Card>,
+        found: Vec<Card>,
+    },
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckError::NotEnoughCards { wanted, left } => {
+                write!(f, "can't take {wanted} cards from a deck of {left}")
+            }
+            DeckError::CardNotInDeck(card) => write!(f, "card `{card}` is not in the deck"),
+            DeckError::ReplayMismatch { expected, found } => write!(
+                f,
+                "replay dealt {} where the log has {}",
+                cards(found),
+                cards(expected)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DeckError {}
+
+/// A deck of cards that logs every shuffle, deal, burn and removal so games can be replayed.
+/// Shuffles are seeded Fisher-Yates shuffles, the same seed always gives the same order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<Card>,
+    log: Vec<DeckEvent>,
+}
+
+impl Deck {
+    /// A standard 52 card deck, spades then hearts, diamonds and clovers, each from 2 to ace.
+    pub fn new() -> Self {
+        let cards = [
+            CardTypes::Spades,
+            CardTypes::Hearts,
+            CardTypes::Diamonds,
+            CardTypes::Clovers,
+        ]
+        .iter()
+        .flat_map(|&card_type| (2..=14).map(move |value| Card { value, card_type }))
+        .collect();
+
+        Self { cards, log: vec![] }
+    }
+
+    /// A standard deck shuffled with `seed`.
+    pub fn shuffled(seed: u64) -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle(seed);
+        deck
+    }
+
+    /// Rebuilds a deck by replaying a log from a standard deck, checking every dealt
+    /// and burned card matches the log.
+    pub fn replay(log: &[DeckEvent]) -> Result<Self, DeckError> {
+        let mut deck = Deck::new();
+
+        for event in log {
+            let (expected, found) = match event {
+                DeckEvent::Shuffle(seed) => {
+                    deck.shuffle(*seed);
+                    continue;
+                }
+                DeckEvent::Remove(dead) => {
+                    deck.remove(dead)?;
+                    continue;
+                }
+                DeckEvent::Deal(cards) => (cards.clone(), deck.deal(cards.len())?),
+                DeckEvent::Burn(card) => (vec![card.clone()], vec![deck.burn()?]),
+            };
+
+            if expected != found {
+                return Err(DeckError::ReplayMismatch { expected, found });
+            }
+        }
+
+        Ok(deck)
+    }
+
+    /// Shuffles the cards left in the deck.
+    pub fn shuffle(&mut self, seed: u64) {
+        let mut rng = Rng::new(seed);
+        for i in (1..self.cards.len()).rev() {
+            let j = rng.below(i + 1);
+            self.cards.swap(i, j);
+        }
+        self.log.push(DeckEvent::Shuffle(seed));
+    }
+
+    /// Deals `n` cards off the top of the deck.
+    pub fn deal(&mut self, n: usize) -> Result<Vec<Card>, DeckError> {
+        if n > self.cards.len() {
+            return Err(DeckError::NotEnoughCards {
+                wanted: n,
+                left: self.cards.len(),
+            });
+        }
+
+        let cards = self.cards.drain(..n).collect::<Vec<Card>>();
+        self.log.push(DeckEvent::Deal(cards.clone()));
+        Ok(cards)
+    }
+
+    /// Discards the top card of the deck face down.
+    pub fn burn(&mut self) -> Result<Card, DeckError> {
+        if self.cards.is_empty() {
+            return Err(DeckError::NotEnoughCards { wanted: 1, left: 0 });
+        }
+
+        let card = self.cards.remove(0);
+        self.log.push(DeckEvent::Burn(card.clone()));
+        Ok(card)
+    }
+
+    /// Takes dead cards out of the deck, leaving it untouched if any of them is missing.
+    pub fn remove(&mut self, dead: &[Card]) -> Result<(), DeckError> {
+        if let Some(card) = dead.iter().find(|card| !self.cards.contains(card)) {
+            return Err(DeckError::CardNotInDeck(card.clone()));
+        }
+
+        self.cards.retain(|card| !dead.contains(card));
+        self.log.push(DeckEvent::Remove(dead.to_vec()));
+        Ok(())
+    }
+
+    /// The cards left in the deck, top card first.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Everything that happened to the deck since it was created.
+    pub fn log(&self) -> &[DeckEvent] {
+        &self.log
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(Card::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,580 @@
+use std::{fmt, sync::Mutex, thread};
+
+use crate::{evaluate, rng::Rng, Card, Deck, Game, PackedCard, Range, Strength};
+
+/// How `equity` deals the rest of the board.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mode {
+    /// Every possible board.
+    Exact,
+    /// `iterations` random boards drawn from `seed`, the same seed always gives the same result.
+    /// Boards are enumerated exactly instead when there are no more of them than `iterations`.
+    MonteCarlo { iterations: usize, seed: u64 },
+}
+
+/// The chances of a player at showdown.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Equity {
+    win: f64,
+    tie: f64,
+    lose: f64,
+    share: f64,
+}
+
+impl Equity {
+    /// Chance of winning the whole pot.
+    pub fn win(&self) -> f64 {
+        self.win
+    }
+
+    /// Chance of splitting the pot.
+    pub fn tie(&self) -> f64 {
+        self.tie
+    }
+
+    pub fn lose(&self) -> f64 {
+        self.lose
+    }
+
+    /// Expected share of the pot, counting a split between n players as 1/n.
+    pub fn share(&self) -> f64 {
+        self.share
+    }
+}
+
+/// Reasons the cards can't be dealt out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquityError {
+    NotEnoughPlayers,
+    TooManyBoardCards(usize),
+    DuplicateCard(Card),
+    /// A player's range has no combos left once the known cards are removed.
+    EmptyRange(usize),
+    /// Every way of dealing the ranges gives two players the same card.
+    ConflictingRanges,
+    /// Jokers aren't in the deck the board is dealt from.
+    UnexpectedJoker,
+    /// A player holds a number of cards other than the game deals.
+    WrongHoleCards {
+        player: usize,
+        found: usize,
+    },
+    /// The deck runs out before every player and the board are dealt.
+    NotEnoughCards {
+        needed: usize,
+        left: usize,
+    },
+    /// Monte Carlo needs at least one board to deal.
+    NoIterations,
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EquityError::NotEnoughPlayers => write!(f, "equity needs at least 2 players"),
+            EquityError::TooManyBoardCards(n) => {
+                write!(f, "a board has at most 5 cards, found {n}")
+            }
+            EquityError::DuplicateCard(card) => write!(f, "card `{card}` is dealt more than once"),
+            EquityError::EmptyRange(player) => {
+                write!(
+                    f,
+                    "the range of player {player} is empty once known cards are removed"
+                )
+            }
+            EquityError::ConflictingRanges => write!(f, "the ranges can't all be dealt at once"),
+            EquityError::UnexpectedJoker => write!(f, "jokers can't be dealt for equity"),
+            EquityError::WrongHoleCards { player, found } => {
+                write!(f, "player {player} holds {found} cards instead of 2")
+            }
+            EquityError::NotEnoughCards { needed, left } => {
+                write!(f, "dealing needs {needed} cards but only {left} are left")
+            }
+            EquityError::NoIterations => write!(f, "Monte Carlo needs at least 1 iteration"),
+        }
+    }
+}
+
+impl std::error::Error for EquityError {}
+
+/// Calculates the Texas Hold'em equity of each player from their hole cards and a partial board,
+/// `dead` cards are known to be out of the deck. The work is spread across all available cores.
+pub fn equity(
+    players: &[Vec<Card>],
+    board: &[Card],
+    dead: &[Card],
+    mode: Mode,
+) -> Result<Vec<Equity>, EquityError> {
+    if players.len() < 2 {
+        return Err(EquityError::NotEnoughPlayers);
+    }
+    check_mode(mode)?;
+    let hole_cards = Game::Holdem.hole_cards();
+    if let Some((player, hole)) = players
+        .iter()
+        .enumerate()
+        .find(|(_, hole)| hole.len() != hole_cards)
+    {
+        return Err(EquityError::WrongHoleCards {
+            player,
+            found: hole.len(),
+        });
+    }
+    let known = players.iter().flatten().cloned().collect::<Vec<Card>>();
+    let deck = remaining_deck(&known, board, dead, 0)?;
+    let table = Table {
+        players: players
+            .iter()
+            .map(|hole| hole.iter().map(PackedCard::from).collect())
+            .collect(),
+        board: board.iter().map(PackedCard::from).collect(),
+        missing: 5 - board.len(),
+    };
+
+    let tally = match mode {
+        Mode::MonteCarlo { iterations, seed }
+            if (choose(deck.len(), table.missing) as usize) > iterations =>
+        {
+            table.sample(&deck, iterations, seed)
+        }
+        _ => table.enumerate(&deck),
+    };
+
+    Ok(tally.equities())
+}
+
+/// Like `equity`, but each player holds any combo of a range, as often as its weight says.
+/// Combos holding a board or dead card are left out, as are deals giving two players a card.
+pub fn range_equity(
+    ranges: &[Range],
+    board: &[Card],
+    dead: &[Card],
+    mode: Mode,
+) -> Result<Vec<Equity>, EquityError> {
+    if ranges.len() < 2 {
+        return Err(EquityError::NotEnoughPlayers);
+    }
+    check_mode(mode)?;
+    let deck = remaining_deck(&[], board, dead, ranges.len() * Game::Holdem.hole_cards())?;
+    let known = [board, dead].concat();
+    let ranges = ranges
+        .iter()
+        .enumerate()
+        .map(|(player, range)| match range.without(&known) {
+            range if range.is_empty() => Err(EquityError::EmptyRange(player)),
+            range => Ok(range),
+        })
+        .collect::<Result<Vec<Range>, EquityError>>()?;
+
+    let mut deals = vec![];
+    let deal = |deals: &mut Vec<(Vec<Card>, f64)>, limit| {
+        deal_ranges(&ranges, &mut vec![], 1.0, deals, limit)
+    };
+    let boards = choose(deck.len() - 2 * ranges.len(), 5 - board.len()) as u128;
+    let deals_at_most = ranges.iter().map(|r| r.len() as u128).product::<u128>();
+
+    match mode {
+        Mode::MonteCarlo { iterations, seed } if deals_at_most * boards > iterations as u128 => {
+            // One deal is enough to know the sampling below ends
+            deal(&mut deals, 1);
+            if deals.is_empty() {
+                return Err(EquityError::ConflictingRanges);
+            }
+            let table = RangeTable {
+                ranges: ranges.iter().map(WeightedCombos::new).collect(),
+                board: board.iter().map(PackedCard::from).collect(),
+                deck,
+            };
+            Ok(table.sample(iterations, seed).equities())
+        }
+        _ => {
+            deal(&mut deals, usize::MAX);
+            if deals.is_empty() {
+                return Err(EquityError::ConflictingRanges);
+            }
+
+            // Every deal leaves as many cards for the board, so equities average by weight
+            let total = deals.iter().map(|(_, weight)| weight).sum::<f64>();
+            let mut average = vec![Equity::default(); ranges.len()];
+            for (cards, weight) in &deals {
+                let players = cards.chunks(2).map(<[Card]>::to_vec).collect::<Vec<_>>();
+                let equities = equity(&players, board, dead, Mode::Exact)?;
+                for (average, equity) in average.iter_mut().zip(equities) {
+                    let share = weight / total;
+                    average.win += equity.win * share;
+                    average.tie += equity.tie * share;
+                    average.lose += equity.lose * share;
+                    average.share += equity.share * share;
+                }
+            }
+            Ok(average)
+        }
+    }
+}
+
+// The deck without the hole, board and dead cards, checking none is dealt twice and that
+// `hole_cards` more can be dealt from it with the rest of the board
+fn remaining_deck(
+    hole: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    hole_cards: usize,
+) -> Result<Vec<PackedCard>, EquityError> {
+    if board.len() > 5 {
+        return Err(EquityError::TooManyBoardCards(board.len()));
+    }
+
+    let mut seen: Vec<&Card> = vec![];
+    for card in hole.iter().chain(board).chain(dead) {
+        if card.is_joker() {
+            return Err(EquityError::UnexpectedJoker);
+        } else if seen.contains(&card) {
+            return Err(EquityError::DuplicateCard(card.clone()));
+        }
+        seen.push(card);
+    }
+
+    let deck = Deck::new()
+        .cards()
+        .iter()
+        .filter(|card| !seen.contains(card))
+        .map(PackedCard::from)
+        .collect::<Vec<PackedCard>>();
+    let needed = hole_cards + 5 - board.len();
+    match deck.len() < needed {
+        true => Err(EquityError::NotEnoughCards {
+            needed,
+            left: deck.len(),
+        }),
+        false => Ok(deck),
+    }
+}
+
+// Monte Carlo without iterations has no boards to average over
+fn check_mode(mode: Mode) -> Result<(), EquityError> {
+    match mode {
+        Mode::MonteCarlo { iterations: 0, .. } => Err(EquityError::NoIterations),
+        _ => Ok(()),
+    }
+}
+
+// Collects up to `limit` deals of one combo per range with no card dealt twice, and their weights
+fn deal_ranges(
+    ranges: &[Range],
+    dealt: &mut Vec<Card>,
+    weight: f64,
+    deals: &mut Vec<(Vec<Card>, f64)>,
+    limit: usize,
+) {
+    let Some((range, rest)) = ranges.split_first() else {
+        deals.push((dealt.clone(), weight));
+        return;
+    };
+    for combo in range.combos() {
+        if deals.len() >= limit {
+            return;
+        }
+        if !combo.blocks(dealt) {
+            dealt.extend(combo.cards().iter().cloned());
+            deal_ranges(rest, dealt, weight * combo.weight(), deals, limit);
+            dealt.truncate(dealt.len() - 2);
+        }
+    }
+}
+
+// A range ready for drawing combos in proportion to their weight
+struct WeightedCombos {
+    combos: Vec<[PackedCard; 2]>,
+    cumulative: Vec<f64>,
+}
+
+impl WeightedCombos {
+    fn new(range: &Range) -> Self {
+        let mut total = 0.0;
+        Self {
+            combos: range
+                .combos()
+                .iter()
+                .map(|combo| {
+                    [
+                        PackedCard::from(&combo.cards()[0]),
+                        PackedCard::from(&combo.cards()[1]),
+                    ]
+                })
+                .collect(),
+            cumulative: range
+                .combos()
+                .iter()
+                .map(|combo| {
+                    total += combo.weight();
+                    total
+                })
+                .collect(),
+        }
+    }
+
+    fn draw(&self, rng: &mut Rng) -> [PackedCard; 2] {
+        // 53 random bits make a uniform float in 0..1
+        let x = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        let target = x * self.cumulative[self.cumulative.len() - 1];
+        let i = self.cumulative.partition_point(|&c| c <= target);
+        self.combos[i.min(self.combos.len() - 1)]
+    }
+}
+
+// The ranges and known cards of `range_equity`
+struct RangeTable {
+    ranges: Vec<WeightedCombos>,
+    board: Vec<PackedCard>,
+    deck: Vec<PackedCard>,
+}
+
+impl RangeTable {
+    // Draws a deal and a board per iteration, batched and seeded like `Table::sample`.
+    // Deals giving a card twice are drawn again, which keeps the weights of the others in proportion.
+    fn sample(&self, iterations: usize, seed: u64) -> Tally {
+        const BATCH: usize = 1_000;
+        let batches = iterations.div_ceil(BATCH);
+        let players = self.ranges.len();
+        let missing = 5 - self.board.len();
+        let next = Mutex::new(0);
+
+        run_parallel(players, || {
+            let mut tally = Tally::new(players);
+            let mut hole = Vec::with_capacity(players);
+            let mut shuffled = Vec::with_capacity(self.deck.len());
+            let mut cards = Vec::with_capacity(7);
+            loop {
+                let batch = {
+                    let mut next = next.lock().unwrap();
+                    *next += 1;
+                    *next - 1
+                };
+                if batch >= batches {
+                    return tally;
+                }
+
+                let mut rng = Rng::new(seed ^ Rng::new(batch as u64).next_u64());
+                for _ in 0..BATCH.min(iterations - batch * BATCH) {
+                    hole.clear();
+                    while hole.len() < players {
+                        let combo = self.ranges[hole.len()].draw(&mut rng);
+                        match hole.iter().flatten().any(|card| combo.contains(card)) {
+                            true => hole.clear(),
+                            false => hole.push(combo),
+                        }
+                    }
+
+                    shuffled.clear();
+                    shuffled.extend(
+                        self.deck
+                            .iter()
+                            .filter(|card| !hole.iter().flatten().any(|c| c == *card)),
+                    );
+                    for i in 0..missing {
+                        let j = i + rng.below(shuffled.len() - i);
+                        shuffled.swap(i, j);
+                    }
+
+                    let strengths = hole
+                        .iter()
+                        .map(|combo| {
+                            cards.clear();
+                            cards.extend(
+                                combo.iter().chain(&self.board).chain(&shuffled[..missing]),
+                            );
+                            evaluate(&cards)
+                        })
+                        .collect();
+                    tally.add(strengths);
+                }
+            }
+        })
+    }
+}
+
+// The cards known at the table and how many board cards are still to come
+struct Table {
+    players: Vec<Vec<PackedCard>>,
+    board: Vec<PackedCard>,
+    missing: usize,
+}
+
+impl Table {
+    // Deals every board, each thread taking the boards starting with a different card
+    fn enumerate(&self, deck: &[PackedCard]) -> Tally {
+        if self.missing == 0 {
+            let mut tally = Tally::new(self.players.len());
+            tally.add(self.showdown(&[]));
+            return tally;
+        }
+
+        let next = Mutex::new(0);
+        run_parallel(self.players.len(), || {
+            let mut tally = Tally::new(self.players.len());
+            let mut runout = Vec::with_capacity(self.missing);
+            loop {
+                let first = {
+                    let mut next = next.lock().unwrap();
+                    *next += 1;
+                    *next - 1
+                };
+                if first >= deck.len() {
+                    return tally;
+                }
+                runout.clear();
+                runout.push(deck[first]);
+                self.deal_rest(deck, first + 1, &mut runout, &mut tally);
+            }
+        })
+    }
+
+    fn deal_rest(
+        &self,
+        deck: &[PackedCard],
+        from: usize,
+        runout: &mut Vec<PackedCard>,
+        tally: &mut Tally,
+    ) {
+        if runout.len() == self.missing {
+            tally.add(self.showdown(runout));
+            return;
+        }
+        for i in from..deck.len() {
+            runout.push(deck[i]);
+            self.deal_rest(deck, i + 1, runout, tally);
+            runout.pop();
+        }
+    }
+
+    // Deals random boards in fixed size batches, each seeded from `seed` and its number,
+    // so the result doesn't depend on how many threads run them
+    fn sample(&self, deck: &[PackedCard], iterations: usize, seed: u64) -> Tally {
+        const BATCH: usize = 1_000;
+        let batches = iterations.div_ceil(BATCH);
+        let next = Mutex::new(0);
+
+        run_parallel(self.players.len(), || {
+            let mut tally = Tally::new(self.players.len());
+            let mut shuffled = deck.to_vec();
+            loop {
+                let batch = {
+                    let mut next = next.lock().unwrap();
+                    *next += 1;
+                    *next - 1
+                };
+                if batch >= batches {
+                    return tally;
+                }
+
+                let mut rng = Rng::new(seed ^ Rng::new(batch as u64).next_u64());
+                shuffled.copy_from_slice(deck);
+                for _ in 0..BATCH.min(iterations - batch * BATCH) {
+                    // A partial Fisher-Yates shuffle puts the runout at the front of the deck
+                    for i in 0..self.missing {
+                        let j = i + rng.below(shuffled.len() - i);
+                        shuffled.swap(i, j);
+                    }
+                    tally.add(self.showdown(&shuffled[..self.missing]));
+                }
+            }
+        })
+    }
+
+    fn showdown(&self, runout: &[PackedCard]) -> Vec<Strength> {
+        let mut cards = Vec::with_capacity(7);
+        self.players
+            .iter()
+            .map(|hole| {
+                cards.clear();
+                cards.extend(hole.iter().chain(&self.board).chain(runout));
+                evaluate(&cards)
+            })
+            .collect()
+    }
+}
+
+// Showdown results counted per player. Pot shares are counted in units of 1/unit, a multiple
+// of every possible number of winners, so tallies add up exactly in any order.
+#[derive(Clone)]
+struct Tally {
+    boards: u64,
+    wins: Vec<u64>,
+    ties: Vec<u64>,
+    shares: Vec<u128>,
+    unit: u128,
+}
+
+impl Tally {
+    fn new(players: usize) -> Self {
+        Self {
+            boards: 0,
+            wins: vec![0; players],
+            ties: vec![0; players],
+            shares: vec![0; players],
+            unit: (1..=players as u128).fold(1, |lcm, n| lcm * n / gcd(lcm, n)),
+        }
+    }
+
+    fn add(&mut self, strengths: Vec<Strength>) {
+        let best = strengths.iter().max().unwrap();
+        let winners = strengths.iter().filter(|s| *s == best).count();
+
+        self.boards += 1;
+        for (player, strength) in strengths.iter().enumerate() {
+            if strength == best {
+                match winners {
+                    1 => self.wins[player] += 1,
+                    _ => self.ties[player] += 1,
+                }
+                self.shares[player] += self.unit / winners as u128;
+            }
+        }
+    }
+
+    fn merge(mut self, other: Tally) -> Self {
+        self.boards += other.boards;
+        for i in 0..self.wins.len() {
+            self.wins[i] += other.wins[i];
+            self.ties[i] += other.ties[i];
+            self.shares[i] += other.shares[i];
+        }
+        self
+    }
+
+    fn equities(&self) -> Vec<Equity> {
+        let boards = self.boards as f64;
+        (0..self.wins.len())
+            .map(|i| Equity {
+                win: self.wins[i] as f64 / boards,
+                tie: self.ties[i] as f64 / boards,
+                lose: (self.boards - self.wins[i] - self.ties[i]) as f64 / boards,
+                share: self.shares[i] as f64 / self.unit as f64 / boards,
+            })
+            .collect()
+    }
+}
+
+// Runs `work` on every available core and merges the tallies
+fn run_parallel(players: usize, work: impl Fn() -> Tally + Sync) -> Tally {
+    let workers = thread::available_parallelism().map_or(1, |n| n.get());
+
+    thread::scope(|s| {
+        let handles = (0..workers).map(|_| s.spawn(&work)).collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .fold(Tally::new(players), Tally::merge)
+    })
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+fn choose(n: usize, k: usize) -> u64 {
+    (0..k as u64).fold(1, |acc, i| acc * (n as u64 - i) / (i + 1))
+}
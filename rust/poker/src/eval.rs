@@ -0,0 +1,341 @@
+// Table based hand evaluator in the style of Cactus Kev's, every card is packed into 32 bits:
+//
+//     xxxbbbbb bbbbbbbb cdhsrrrr xxpppppp
+//
+// b: one bit per rank, cdhs: the suit, r: rank index (deuce = 0, ace = 12), p: prime of the rank.
+//
+// The 7462 distinct five card hands are ranked once with `HandRank` and stored in tables keyed by
+// the OR of the rank bits (flushes and hands of five distinct ranks) or the product of the primes
+// (hands holding pairs), so evaluating a hand needs no sorting or allocation.
+//
+// Six and seven card hands get their own tables: a flush can't be beaten by the other cards in
+// seven cards, so the best hand either comes from the ranks of the flush suit or from the ranks alone.
+
+use std::sync::OnceLock;
+
+use crate::{Card, CardTypes, Category, Hand, HandRank};
+
+const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// A card packed into 32 bits for fast evaluation.
+///
+/// Jokers can't be packed, converting one panics.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PackedCard(u32);
+
+impl PackedCard {
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<&Card> for PackedCard {
+    fn from(card: &Card) -> Self {
+        let rank = (card.value - 2) as u32;
+        let suit = match card.card_type {
+            CardTypes::Spades => 0x1000,
+            CardTypes::Hearts => 0x2000,
+            CardTypes::Diamonds => 0x4000,
+            CardTypes::Clovers => 0x8000,
+            CardTypes::Joker => panic!("jokers only play through a RuleSet"),
+        };
+
+        PackedCard((1 << (16 + rank)) | suit | (rank << 8) | PRIMES[rank as usize])
+    }
+}
+
+/// Strength of a five card hand from 1 (7-5-4-3-2 offsuit) to 7462 (royal flush),
+/// two hands have the same strength exactly when their `HandRank`s are equal.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Strength(u16);
+
+impl Strength {
+    pub fn value(self) -> u16 {
+        self.0
+    }
+
+    pub fn category(self) -> Category {
+        tables().categories[self.0 as usize]
+    }
+}
+
+/// Evaluates the best five card hand out of five or more packed cards.
+///
+/// # Panics
+///
+/// Panics if fewer than five cards are given, and in debug builds if a card appears twice.
+/// Release builds give an unspecified strength for such cards.
+pub fn evaluate(cards: &[PackedCard]) -> Strength {
+    let n = cards.len();
+    assert!(n >= 5, "a hand needs at least 5 cards, found {n}");
+    debug_assert!(distinct(cards), "a card appears more than once");
+
+    match n {
+        5 => evaluate5([cards[0], cards[1], cards[2], cards[3], cards[4]]),
+        6 | 7 => evaluate7(cards),
+        _ => {
+            let mut best = Strength(0);
+            for a in 0..n {
+                for b in a + 1..n {
+                    for c in b + 1..n {
+                        for d in c + 1..n {
+                            for e in d + 1..n {
+                                let hand = [cards[a], cards[b], cards[c], cards[d], cards[e]];
+                                best = best.max(evaluate5(hand));
+                            }
+                        }
+                    }
+                }
+            }
+            best
+        }
+    }
+}
+
+fn distinct(cards: &[PackedCard]) -> bool {
+    (1..cards.len()).all(|i| !cards[..i].contains(&cards[i]))
+}
+
+// Evaluates six or seven cards
+fn evaluate7(cards: &[PackedCard]) -> Strength {
+    let tables = tables7();
+    let mut suits = [0; 4];
+    let mut suit_ranks = [0; 4];
+    let mut product = 1;
+
+    for card in cards {
+        let suit = (card.0 >> 12 & 0xF).trailing_zeros() as usize;
+        suits[suit] += 1;
+        suit_ranks[suit] |= (card.0 >> 16) as usize;
+        product *= (card.0 & 0xFF) as u64;
+    }
+
+    if let Some(suit) = (0..4).find(|&suit| suits[suit] >= 5) {
+        return Strength(tables.flushes[suit_ranks[suit]]);
+    }
+
+    let i = tables
+        .products
+        .binary_search_by_key(&product, |&(p, _)| p)
+        .expect("every product of primes with at most four alike is a hand");
+    Strength(tables.products[i].1)
+}
+
+/// Evaluates exactly five packed cards.
+///
+/// # Panics
+///
+/// Panics in debug builds if a card appears twice, release builds give an unspecified strength.
+pub fn evaluate5(cards: [PackedCard; 5]) -> Strength {
+    debug_assert!(distinct(&cards), "a card appears more than once");
+    let tables = tables();
+    let [a, b, c, d, e] = cards.map(PackedCard::bits);
+    let ranks = ((a | b | c | d | e) >> 16) as usize;
+
+    if a & b & c & d & e & 0xF000 != 0 {
+        return Strength(tables.flushes[ranks]);
+    }
+    if tables.unique5[ranks] != 0 {
+        return Strength(tables.unique5[ranks]);
+    }
+
+    let product = (a & 0xFF) * (b & 0xFF) * (c & 0xFF) * (d & 0xFF) * (e & 0xFF);
+    Strength(tables.paired(product))
+}
+
+struct Tables {
+    flushes: Vec<u16>,
+    unique5: Vec<u16>,
+    products: Vec<(u32, u16)>,
+    categories: Vec<Category>,
+}
+
+// Best strength out of six or seven cards
+struct Tables7 {
+    flushes: Vec<u16>,
+    products: Vec<(u64, u16)>,
+}
+
+// Which table a class of hands is stored in and under what key
+enum Key {
+    Flush(usize),
+    Unique(usize),
+    Product(u32),
+}
+
+fn tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+    TABLES.get_or_init(Tables::build)
+}
+
+fn tables7() -> &'static Tables7 {
+    static TABLES: OnceLock<Tables7> = OnceLock::new();
+    TABLES.get_or_init(Tables7::build)
+}
+
+impl Tables {
+    fn build() -> Tables {
+        let mut classes = vec![];
+        rank_counts(&mut [0; 13], 12, 5, &mut |counts| {
+            let values = values(counts);
+            let ranks = values.iter().fold(0, |mask, rank| mask | 1 << rank);
+
+            if values.len() == counts.iter().filter(|&&n| n > 0).count() {
+                let flush = values.iter().map(|&rank| card(rank, CardTypes::Spades));
+                classes.push((rank_of(flush.collect()), Key::Flush(ranks)));
+
+                // One off suit card is enough to break the flush
+                let offsuit = values.iter().enumerate().map(|(i, &rank)| match i {
+                    0 => card(rank, CardTypes::Hearts),
+                    _ => card(rank, CardTypes::Spades),
+                });
+                classes.push((rank_of(offsuit.collect()), Key::Unique(ranks)));
+            } else {
+                // Cards of the same rank get different suits
+                let mut seen = [0; 13];
+                let cards = values.iter().map(|&rank| {
+                    seen[rank] += 1;
+                    card(rank, SUITS[seen[rank] - 1])
+                });
+                let product = values.iter().map(|&rank| PRIMES[rank]).product();
+                classes.push((rank_of(cards.collect()), Key::Product(product)));
+            }
+        });
+        classes.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut tables = Tables {
+            flushes: vec![0; 1 << 13],
+            unique5: vec![0; 1 << 13],
+            products: vec![],
+            categories: vec![Category::HighCard],
+        };
+        for (i, (rank, key)) in classes.into_iter().enumerate() {
+            let strength = i as u16 + 1;
+            match key {
+                Key::Flush(ranks) => tables.flushes[ranks] = strength,
+                Key::Unique(ranks) => tables.unique5[ranks] = strength,
+                Key::Product(product) => tables.products.push((product, strength)),
+            }
+            tables.categories.push(rank.category());
+        }
+        tables.products.sort_unstable();
+        tables
+    }
+
+    // Strength of five cards that aren't a flush, from the OR of their rank bits and product of their primes
+    fn non_flush(&self, ranks: usize, product: u32) -> u16 {
+        match self.unique5[ranks] {
+            0 => self.paired(product),
+            strength => strength,
+        }
+    }
+
+    fn paired(&self, product: u32) -> u16 {
+        let i = self
+            .products
+            .binary_search_by_key(&product, |&(p, _)| p)
+            .expect("every product of five primes with at most four alike is a hand");
+        self.products[i].1
+    }
+}
+
+impl Tables7 {
+    fn build() -> Tables7 {
+        let tables = tables();
+
+        // The best flush out of every set of five or more ranks
+        let flushes = (0..1 << 13)
+            .map(|ranks: usize| match ranks.count_ones() {
+                5.. => subsets(&bits(ranks), &mut |hand| {
+                    tables.flushes[hand.iter().fold(0, |mask, rank| mask | 1 << rank)]
+                }),
+                _ => 0,
+            })
+            .collect();
+
+        let mut products = vec![];
+        for n in [6, 7] {
+            rank_counts(&mut [0; 13], 12, n, &mut |counts| {
+                let values = values(counts);
+                let best = subsets(&values, &mut |hand| {
+                    let ranks = hand.iter().fold(0, |mask, rank| mask | 1 << rank);
+                    tables.non_flush(ranks, hand.iter().map(|&rank| PRIMES[rank]).product())
+                });
+                products.push((
+                    values.iter().map(|&rank| PRIMES[rank] as u64).product(),
+                    best,
+                ));
+            });
+        }
+        products.sort_unstable();
+
+        Tables7 { flushes, products }
+    }
+}
+
+const SUITS: [CardTypes; 4] = [
+    CardTypes::Spades,
+    CardTypes::Hearts,
+    CardTypes::Diamonds,
+    CardTypes::Clovers,
+];
+
+// The ranks held in `counts`, highest first
+fn values(counts: &[usize; 13]) -> Vec<usize> {
+    (0..13)
+        .rev()
+        .flat_map(|rank| std::iter::repeat_n(rank, counts[rank]))
+        .collect()
+}
+
+// The ranks set in a mask of rank bits
+fn bits(ranks: usize) -> Vec<usize> {
+    (0..13).filter(|rank| ranks & 1 << rank != 0).collect()
+}
+
+// The highest strength `f` gives to any five of the ranks
+fn subsets(ranks: &[usize], f: &mut impl FnMut([usize; 5]) -> u16) -> u16 {
+    let n = ranks.len();
+    let mut best = 0;
+    for a in 0..n {
+        for b in a + 1..n {
+            for c in b + 1..n {
+                for d in c + 1..n {
+                    for e in d + 1..n {
+                        best = best.max(f([ranks[a], ranks[b], ranks[c], ranks[d], ranks[e]]));
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+fn card(rank: usize, card_type: CardTypes) -> Card {
+    Card {
+        value: rank as i32 + 2,
+        card_type,
+    }
+}
+
+fn rank_of(cards: Vec<Card>) -> HandRank {
+    Hand::sorted(cards).rank()
+}
+
+// Calls `f` with every way of picking `left` cards out of the ranks up to `rank`, at most four of each
+fn rank_counts(
+    counts: &mut [usize; 13],
+    rank: usize,
+    left: usize,
+    f: &mut impl FnMut(&[usize; 13]),
+) {
+    for n in (0..=left.min(4)).rev() {
+        counts[rank] = n;
+        if n == left {
+            f(counts);
+        } else if rank > 0 {
+            rank_counts(counts, rank - 1, left - n, f);
+        }
+    }
+    counts[rank] = 0;
+}
@@ -0,0 +1,748 @@
+// Reads and writes hands in the text format of PokerStars hand histories, e.g.
+//
+//     PokerStars Hand #1: Hold'em No Limit (5/10)
+//     Table 'Replay' 3-max Seat #1 is the button
+//     Seat 1: alice (1000 in chips)
+//     ...
+//     bob: posts small blind 5
+//     *** HOLE CARDS ***
+//     Dealt to alice [Ah Kd]
+//     alice: raises 20 to 30
+//     *** FLOP *** [2c 7d 9h]
+//     ...
+//     *** SUMMARY ***
+//
+// Everything after the summary header is derived from the rest and skipped when reading, as are
+// lines which change nothing such as chat, players sitting out and hands mucked. Hands played for
+// money give amounts such as `$0.02`, which are read in cents.
+
+use std::{fmt, str::FromStr};
+
+use crate::{best_five, Card, Compact, Event, Hand, Notation, ParseError, Street, TableConfig};
+
+/// A player dealt into a hand, `seat` is the table seat starting from 0.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Player {
+    pub seat: usize,
+    pub name: String,
+    pub stack: u64,
+}
+
+/// The money a cash game is played for.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Currency {
+    /// `$`, `€` or `£`.
+    pub symbol: char,
+    /// Such as `USD`, when the header gives one.
+    pub code: Option<String>,
+}
+
+/// One hand as the table logged it, minus the deck seed which the text format can't hold.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct HandHistory {
+    pub hand: u64,
+    pub table: String,
+    pub max_seats: usize,
+    pub config: TableConfig,
+    /// Every amount is in cents of the currency when there is one, otherwise in chips.
+    pub currency: Option<Currency>,
+    pub button: usize,
+    pub players: Vec<Player>,
+    pub events: Vec<Event>,
+}
+
+/// Reasons a hand history can't be read, with the line number starting from 1. For
+/// `from_events` the line is the number of the event, also starting from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistoryError {
+    Malformed { line: usize, text: String },
+    UnknownPlayer { line: usize, name: String },
+    BadCard { line: usize, error: ParseError },
+    MissingHeader,
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Malformed { line, text } => write!(f, "line {line}: can't read `{text}`"),
+            HistoryError::UnknownPlayer { line, name } => {
+                write!(f, "line {line}: `{name}` is not seated")
+            }
+            HistoryError::BadCard { line, error } => write!(f, "line {line}: {error}"),
+            HistoryError::MissingHeader => write!(f, "the hand has no header"),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+impl HandHistory {
+    /// Splits a table log into one history per hand. `names` and `stacks` are those the table
+    /// was created with, stacks are followed through the log to know them at the start of each hand.
+    /// A raise to no more than the bet before it is `Malformed`, as it can't be written.
+    pub fn from_events(
+        table: &str,
+        config: TableConfig,
+        names: &[&str],
+        stacks: &[u64],
+        events: &[Event],
+    ) -> Result<Vec<HandHistory>, HistoryError> {
+        let mut stacks = stacks.to_vec();
+        let mut histories: Vec<HandHistory> = vec![];
+        let mut bets = vec![0; stacks.len()];
+        let mut current_bet = config.big_blind;
+
+        for (i, event) in events.iter().enumerate() {
+            if let Event::Raise { to, .. } = *event {
+                if to <= current_bet {
+                    return Err(HistoryError::Malformed {
+                        line: i + 1,
+                        text: format!("{event:?}"),
+                    });
+                }
+            }
+            if let Event::HandStarted { hand, button, .. } = *event {
+                histories.push(HandHistory {
+                    hand: hand.into(),
+                    table: table.to_string(),
+                    max_seats: names.len(),
+                    config,
+                    currency: None,
+                    button,
+                    players: (0..names.len())
+                        .filter(|&seat| stacks[seat] > 0)
+                        .map(|seat| Player {
+                            seat,
+                            name: names[seat].to_string(),
+                            stack: stacks[seat],
+                        })
+                        .collect(),
+                    events: vec![],
+                });
+                bets.iter_mut().for_each(|bet| *bet = 0);
+                current_bet = config.big_blind;
+                continue;
+            }
+
+            match *event {
+                Event::Ante { seat, amount } => stacks[seat] -= amount,
+                Event::SmallBlind { seat, amount }
+                | Event::BigBlind { seat, amount }
+                | Event::Call { seat, amount, .. } => {
+                    stacks[seat] -= amount;
+                    bets[seat] += amount;
+                }
+                Event::Bet {
+                    seat, amount: to, ..
+                }
+                | Event::Raise { seat, to, .. } => {
+                    stacks[seat] -= to - bets[seat];
+                    bets[seat] = to;
+                    current_bet = to;
+                }
+                Event::Board { .. } => {
+                    bets.iter_mut().for_each(|bet| *bet = 0);
+                    current_bet = 0;
+                }
+                Event::UncalledBet { seat, amount } | Event::Won { seat, amount, .. } => {
+                    stacks[seat] += amount
+                }
+                _ => {}
+            }
+            if let Some(history) = histories.last_mut() {
+                history.events.push(event.clone());
+            }
+        }
+
+        Ok(histories)
+    }
+
+    /// Reads every hand of a file, hands are separated by blank lines.
+    pub fn parse_all(text: &str) -> Result<Vec<HandHistory>, HistoryError> {
+        let mut histories = vec![];
+        let mut start = 0;
+        let lines = text.lines().collect::<Vec<&str>>();
+
+        for (i, line) in lines.iter().enumerate() {
+            if line.starts_with("PokerStars Hand #") && i > start {
+                histories.push(parse(&lines[start..i], start)?);
+                start = i;
+            }
+        }
+        if lines[start..].iter().any(|line| !line.trim().is_empty()) {
+            histories.push(parse(&lines[start..], start)?);
+        }
+
+        Ok(histories)
+    }
+
+    pub fn player(&self, seat: usize) -> Option<&Player> {
+        self.players.iter().find(|player| player.seat == seat)
+    }
+
+    /// All the board cards dealt.
+    pub fn board(&self) -> Vec<Card> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Board { cards, .. } => Some(cards.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// The hole cards of a player if they were dealt face up or shown.
+    pub fn hole_cards(&self, seat: usize) -> Option<Vec<Card>> {
+        self.events.iter().find_map(|event| match event {
+            Event::HoleCards { seat: s, cards } | Event::Showdown { seat: s, cards, .. }
+                if *s == seat =>
+            {
+                Some(cards.clone())
+            }
+            _ => None,
+        })
+    }
+
+    /// The best five card hand of every player who showed down, ready for `winning_hands`.
+    /// Cards shown without five to make a hand from are left out.
+    pub fn showdown_hands(&self) -> Vec<(usize, Hand)> {
+        let board = self.board();
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Showdown { seat, cards, .. } => {
+                    let cards = [cards.as_slice(), &board].concat();
+                    (cards.len() >= 5).then(|| (*seat, best_five(&cards).0))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn name(&self, seat: usize) -> &str {
+        self.player(seat).map_or("?", |player| player.name.as_str())
+    }
+
+    // An amount as the text format writes it, `$1.50` for 150 cents
+    fn money(&self, amount: u64) -> String {
+        match &self.currency {
+            Some(currency) => format!("{}{}.{:02}", currency.symbol, amount / 100, amount % 100),
+            None => amount.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for HandHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let config = self.config;
+        let money = |amount: u64| self.money(amount);
+        write!(
+            f,
+            "PokerStars Hand #{}: Hold'em No Limit ({}/{}",
+            self.hand,
+            money(config.small_blind),
+            money(config.big_blind)
+        )?;
+        if let Some(code) = self.currency.as_ref().and_then(|c| c.code.as_ref()) {
+            write!(f, " {code}")?;
+        }
+        write!(f, ")")?;
+        if config.ante > 0 {
+            write!(f, " - Ante {}", money(config.ante))?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Table '{}' {}-max Seat #{} is the button",
+            self.table,
+            self.max_seats,
+            self.button + 1
+        )?;
+        for player in &self.players {
+            writeln!(
+                f,
+                "Seat {}: {} ({} in chips)",
+                player.seat + 1,
+                player.name,
+                money(player.stack)
+            )?;
+        }
+
+        let pots = self
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Won { pot, .. } => Some(pot + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        let mut board: Vec<Card> = vec![];
+        let mut current_bet = config.big_blind;
+        let mut hole_cards_shown = false;
+        let mut showdown_shown = false;
+        let mut total = 0;
+        let mut both_blinds = false;
+
+        for (i, event) in self.events.iter().enumerate() {
+            let all_in = |all_in: bool| match all_in {
+                true => " and is all-in",
+                false => "",
+            };
+            match event {
+                Event::HandStarted { .. } => {}
+                Event::Ante { seat, amount } => {
+                    writeln!(f, "{}: posts the ante {}", self.name(*seat), money(*amount))?
+                }
+                // A small blind followed by a big blind of the same player is one post
+                Event::SmallBlind { seat, amount } => match self.events.get(i + 1) {
+                    Some(Event::BigBlind {
+                        seat: s,
+                        amount: big,
+                    }) if s == seat => {
+                        both_blinds = true;
+                        writeln!(
+                            f,
+                            "{}: posts small & big blinds {}",
+                            self.name(*seat),
+                            money(amount + big)
+                        )?
+                    }
+                    _ => writeln!(
+                        f,
+                        "{}: posts small blind {}",
+                        self.name(*seat),
+                        money(*amount)
+                    )?,
+                },
+                Event::BigBlind { .. } if both_blinds => both_blinds = false,
+                Event::BigBlind { seat, amount } => writeln!(
+                    f,
+                    "{}: posts big blind {}",
+                    self.name(*seat),
+                    money(*amount)
+                )?,
+                Event::HoleCards { seat, cards } => {
+                    if !hole_cards_shown {
+                        writeln!(f, "*** HOLE CARDS ***")?;
+                        hole_cards_shown = true;
+                    }
+                    writeln!(
+                        f,
+                        "Dealt to {} [{}]",
+                        self.name(*seat),
+                        Compact.format_cards(cards)
+                    )?
+                }
+                Event::Fold { seat } => writeln!(f, "{}: folds", self.name(*seat))?,
+                Event::Check { seat } => writeln!(f, "{}: checks", self.name(*seat))?,
+                Event::Call {
+                    seat,
+                    amount,
+                    all_in: a,
+                } => writeln!(
+                    f,
+                    "{}: calls {}{}",
+                    self.name(*seat),
+                    money(*amount),
+                    all_in(*a)
+                )?,
+                Event::Bet {
+                    seat,
+                    amount,
+                    all_in: a,
+                } => {
+                    current_bet = *amount;
+                    writeln!(
+                        f,
+                        "{}: bets {}{}",
+                        self.name(*seat),
+                        money(*amount),
+                        all_in(*a)
+                    )?
+                }
+                Event::Raise {
+                    seat,
+                    to,
+                    all_in: a,
+                } => {
+                    let by = to.saturating_sub(current_bet);
+                    current_bet = *to;
+                    writeln!(
+                        f,
+                        "{}: raises {} to {}{}",
+                        self.name(*seat),
+                        money(by),
+                        money(*to),
+                        all_in(*a)
+                    )?
+                }
+                Event::Board { street, cards } => {
+                    let name = match street {
+                        Street::Preflop => "PREFLOP",
+                        Street::Flop => "FLOP",
+                        Street::Turn => "TURN",
+                        Street::River => "RIVER",
+                    };
+                    match board.is_empty() {
+                        true => writeln!(f, "*** {name} *** [{}]", Compact.format_cards(cards))?,
+                        false => writeln!(
+                            f,
+                            "*** {name} *** [{}] [{}]",
+                            Compact.format_cards(&board),
+                            Compact.format_cards(cards)
+                        )?,
+                    }
+                    board.extend(cards.iter().cloned());
+                    current_bet = 0;
+                }
+                Event::UncalledBet { seat, amount } => writeln!(
+                    f,
+                    "Uncalled bet ({}) returned to {}",
+                    money(*amount),
+                    self.name(*seat)
+                )?,
+                Event::Showdown { seat, cards, rank } => {
+                    if rank.is_some() && !showdown_shown {
+                        writeln!(f, "*** SHOW DOWN ***")?;
+                        showdown_shown = true;
+                    }
+                    write!(
+                        f,
+                        "{}: shows [{}]",
+                        self.name(*seat),
+                        Compact.format_cards(cards)
+                    )?;
+                    match rank {
+                        Some(rank) => writeln!(f, " ({rank})")?,
+                        None => writeln!(f)?,
+                    }
+                }
+                Event::Won { seat, pot, amount } => {
+                    total += amount;
+                    let pot = match (pots, pot) {
+                        (1, _) => "pot".to_string(),
+                        (_, 0) => "main pot".to_string(),
+                        (_, n) => format!("side pot-{n}"),
+                    };
+                    writeln!(
+                        f,
+                        "{} collected {} from {pot}",
+                        self.name(*seat),
+                        money(*amount)
+                    )?
+                }
+            }
+        }
+
+        writeln!(f, "*** SUMMARY ***")?;
+        writeln!(f, "Total pot {} | Rake {}", money(total), money(0))?;
+        if !board.is_empty() {
+            writeln!(f, "Board [{}]", Compact.format_cards(&board))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for HandHistory {
+    type Err = HistoryError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse(&text.lines().collect::<Vec<&str>>(), 0)
+    }
+}
+
+// Parses the lines of one hand, `offset` is the number of lines before them in the file
+fn parse(lines: &[&str], offset: usize) -> Result<HandHistory, HistoryError> {
+    let mut lines = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| (i + offset + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .take_while(|(_, line)| *line != "*** SUMMARY ***");
+
+    let (n, header) = lines.next().ok_or(HistoryError::MissingHeader)?;
+    let malformed = |line: usize, text: &str| HistoryError::Malformed {
+        line,
+        text: text.to_string(),
+    };
+
+    // PokerStars Hand #1:  Hold'em No Limit ($0.01/$0.02 USD) - 2024/01/01 20:00:00 ET
+    let (hand, stakes) = header
+        .strip_prefix("PokerStars Hand #")
+        .and_then(|rest| rest.split_once(':'))
+        .and_then(|(hand, rest)| {
+            Some((hand, rest.trim_start().strip_prefix("Hold'em No Limit (")?))
+        })
+        .ok_or_else(|| malformed(n, header))?;
+    let (blinds, rest) = stakes.split_once(')').ok_or_else(|| malformed(n, header))?;
+    let (blinds, code) = match blinds.rsplit_once(' ') {
+        Some((blinds, code)) if code.chars().all(|c| c.is_ascii_uppercase()) => {
+            (blinds, Some(code.to_string()))
+        }
+        _ => (blinds, None),
+    };
+    let (small_blind, big_blind) = blinds.split_once('/').ok_or_else(|| malformed(n, header))?;
+    let currency = small_blind
+        .chars()
+        .next()
+        .filter(|symbol| SYMBOLS.contains(symbol))
+        .map(|symbol| Currency { symbol, code });
+    // Whatever follows the ante is the date
+    let ante = match rest.strip_prefix(" - Ante ") {
+        Some(ante) => ante.split(" - ").next().unwrap_or(ante),
+        None if rest.is_empty() || rest.starts_with(" - ") => "0",
+        None => return Err(malformed(n, header)),
+    };
+    let number = |text: &str| amount(text, currency.as_ref()).ok_or_else(|| malformed(n, header));
+    let config = TableConfig {
+        small_blind: number(small_blind)?,
+        big_blind: number(big_blind)?,
+        ante: number(ante)?,
+    };
+    let hand = hand.parse::<u64>().map_err(|_| malformed(n, header))?;
+
+    // Table 'Replay' 3-max Seat #1 is the button
+    let (n, line) = lines.next().ok_or(HistoryError::MissingHeader)?;
+    let (table, rest) = line
+        .strip_prefix("Table '")
+        .and_then(|rest| rest.rsplit_once("' "))
+        .ok_or_else(|| malformed(n, line))?;
+    let (max_seats, button) = rest
+        .strip_suffix(" is the button")
+        .and_then(|rest| rest.split_once("-max Seat #"))
+        .ok_or_else(|| malformed(n, line))?;
+    let (max_seats, button) = match (max_seats.parse::<usize>(), button.parse::<usize>()) {
+        (Ok(max_seats), Ok(button)) if button > 0 => (max_seats, button - 1),
+        _ => return Err(malformed(n, line)),
+    };
+
+    let mut history = HandHistory {
+        hand,
+        table: table.to_string(),
+        max_seats,
+        config,
+        currency,
+        button,
+        players: vec![],
+        events: vec![],
+    };
+    let mut board: Vec<Card> = vec![];
+    let mut current_bet = config.big_blind;
+
+    for (n, line) in lines {
+        let malformed = || malformed(n, line);
+        let number = |text: &str| amount(text, history.currency.as_ref()).ok_or_else(malformed);
+        let seat = |history: &HandHistory, name: &str| {
+            history
+                .players
+                .iter()
+                .find(|player| player.name == name)
+                .map(|player| player.seat)
+                .ok_or_else(|| HistoryError::UnknownPlayer {
+                    line: n,
+                    name: name.to_string(),
+                })
+        };
+        let cards = |text: &str| {
+            Compact
+                .parse_cards(text)
+                .map_err(|error| HistoryError::BadCard { line: n, error })
+        };
+
+        let event = if is_table_talk(line) {
+            continue;
+        } else if let Some(rest) = line.strip_prefix("Seat ") {
+            // Seat 1: alice (1000 in chips)
+            let (index, rest) = rest.split_once(": ").ok_or_else(malformed)?;
+            let (name, stack) = rest
+                .strip_suffix(" in chips)")
+                .and_then(|rest| rest.rsplit_once(" ("))
+                .ok_or_else(malformed)?;
+            let seat = match index.parse::<usize>() {
+                Ok(seat) if seat > 0 => seat - 1,
+                _ => return Err(malformed()),
+            };
+            history.players.push(Player {
+                seat,
+                name: name.to_string(),
+                stack: number(stack)?,
+            });
+            continue;
+        } else if line == "*** HOLE CARDS ***" || line == "*** SHOW DOWN ***" {
+            continue;
+        } else if let Some(rest) = line.strip_prefix("Dealt to ") {
+            let (name, hole) = rest.rsplit_once(" [").ok_or_else(malformed)?;
+            let hole = hole.strip_suffix(']').ok_or_else(malformed)?;
+            Event::HoleCards {
+                seat: seat(&history, name)?,
+                cards: cards(hole)?,
+            }
+        } else if let Some(rest) = line.strip_prefix("*** ") {
+            let (name, rest) = rest.split_once(" *** ").ok_or_else(malformed)?;
+            let street = match name {
+                "FLOP" => Street::Flop,
+                "TURN" => Street::Turn,
+                "RIVER" => Street::River,
+                _ => return Err(malformed()),
+            };
+            // The cards dealt on this street are in the last brackets
+            let (_, dealt) = rest.rsplit_once('[').ok_or_else(malformed)?;
+            let dealt = cards(dealt.strip_suffix(']').ok_or_else(malformed)?)?;
+            board.extend(dealt.iter().cloned());
+            Event::Board {
+                street,
+                cards: dealt,
+            }
+        } else if let Some(rest) = line.strip_prefix("Uncalled bet (") {
+            let (amount, name) = rest.split_once(") returned to ").ok_or_else(malformed)?;
+            Event::UncalledBet {
+                seat: seat(&history, name)?,
+                amount: number(amount)?,
+            }
+        } else if let Some((name, rest)) = line.split_once(" collected ") {
+            let (amount, pot) = rest.split_once(" from ").ok_or_else(malformed)?;
+            let pot = match pot {
+                "pot" | "main pot" => 0,
+                pot => pot
+                    .strip_prefix("side pot-")
+                    .and_then(|n| n.parse().ok())
+                    .ok_or_else(malformed)?,
+            };
+            Event::Won {
+                seat: seat(&history, name)?,
+                pot,
+                amount: number(amount)?,
+            }
+        } else if let Some((name, action)) = line
+            .rsplit_once(": ")
+            .filter(|(name, _)| history.players.iter().any(|player| player.name == *name))
+        {
+            let seat = seat(&history, name)?;
+            let (action, all_in) = match action.strip_suffix(" and is all-in") {
+                Some(action) => (action, true),
+                None => (action, false),
+            };
+            let words = action.split_whitespace().collect::<Vec<&str>>();
+
+            match words.as_slice() {
+                ["posts", "the", "ante", amount] => Event::Ante {
+                    seat,
+                    amount: number(amount)?,
+                },
+                ["posts", "small", "blind", amount] => Event::SmallBlind {
+                    seat,
+                    amount: number(amount)?,
+                },
+                ["posts", "big", "blind", amount] => Event::BigBlind {
+                    seat,
+                    amount: number(amount)?,
+                },
+                // A dead small blind with the big blind, written as the two
+                ["posts", "small", "&", "big", "blinds", amount] => {
+                    let big_blind = history.config.big_blind;
+                    let small_blind = number(amount)?
+                        .checked_sub(big_blind)
+                        .ok_or_else(malformed)?;
+                    history.events.push(Event::SmallBlind {
+                        seat,
+                        amount: small_blind,
+                    });
+                    Event::BigBlind {
+                        seat,
+                        amount: big_blind,
+                    }
+                }
+                ["folds"] => Event::Fold { seat },
+                ["checks"] => Event::Check { seat },
+                ["calls", amount] => Event::Call {
+                    seat,
+                    amount: number(amount)?,
+                    all_in,
+                },
+                ["bets", amount] => Event::Bet {
+                    seat,
+                    amount: number(amount)?,
+                    all_in,
+                },
+                ["raises", _, "to", to] => Event::Raise {
+                    seat,
+                    to: number(to)?,
+                    all_in,
+                },
+                ["shows", ..] => {
+                    let (shown, _) = action
+                        .strip_prefix("shows [")
+                        .and_then(|rest| rest.split_once(']'))
+                        .ok_or_else(malformed)?;
+                    let shown = cards(shown)?;
+                    let all = [shown.as_slice(), &board].concat();
+                    let rank = (all.len() >= 5).then(|| best_five(&all).1);
+                    Event::Showdown {
+                        seat,
+                        cards: shown,
+                        rank,
+                    }
+                }
+                _ => return Err(malformed()),
+            }
+        } else {
+            return Err(malformed());
+        };
+
+        // A raise has to go above the bet, its size being the difference
+        match event {
+            Event::Raise { to, .. } if to <= current_bet => return Err(malformed()),
+            Event::Bet { amount: to, .. } | Event::Raise { to, .. } => current_bet = to,
+            Event::Board { .. } => current_bet = 0,
+            _ => {}
+        }
+        history.events.push(event);
+    }
+
+    Ok(history)
+}
+
+const SYMBOLS: [char; 3] = ['$', '€', '£'];
+
+// A whole number of chips, or with a currency a number of cents such as `$2`, `$0.5` or `$0.05`
+fn amount(text: &str, currency: Option<&Currency>) -> Option<u64> {
+    let digits = |text: &str| !text.is_empty() && text.chars().all(|c| c.is_ascii_digit());
+    let text = text.trim();
+    let Some(currency) = currency else {
+        return text.parse().ok().filter(|_| digits(text));
+    };
+
+    let text = text.strip_prefix(currency.symbol).unwrap_or(text);
+    let (whole, cents) = text.split_once('.').unwrap_or((text, "0"));
+    if !digits(whole) || !digits(cents) || cents.len() > 2 {
+        return None;
+    }
+    let cents = format!("{cents:0<2}").parse::<u64>().ok()?;
+    whole
+        .parse::<u64>()
+        .ok()?
+        .checked_mul(100)?
+        .checked_add(cents)
+}
+
+// Lines about players coming and going, chat and hands kept hidden, none of which changes the
+// hand. Seats of players sitting out are among them, as they aren't dealt in.
+fn is_table_talk(line: &str) -> bool {
+    const ENDINGS: [&str; 10] = [
+        ": mucks hand",
+        ": doesn't show hand",
+        " is sitting out",
+        " sits out",
+        " has timed out",
+        " is disconnected",
+        " is connected",
+        " has returned",
+        " leaves the table",
+        " will be allowed to play after the button",
+    ];
+    ENDINGS.iter().any(|ending| line.ends_with(ending))
+        || line.contains(" said, \"")
+        || line.contains(" joins the table at seat #")
+}
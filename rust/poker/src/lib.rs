@@ -1,238 +1,414 @@
-use std::{cmp::Ordering, collections::HashMap};
-
-type Hand = Vec<Card>;
-
-// Macro to create a hand from a string of cards or a list of card literals.
-macro_rules! hand {
-    ($hand_string:expr) => {
-       $hand_string.split_whitespace().map(Card::from).collect::<Hand>()
-    };
-    ($($card:literal),+) => {
-        vec![$(Cards::from($card),)+]
-    };
-}
+use std::{cmp::Reverse, collections::HashMap, fmt};
+
+mod deck;
+mod equity;
+mod eval;
+mod history;
+mod notation;
+mod range;
+mod rng;
+mod rules;
+mod showdown;
+mod table;
+
+pub use deck::{Deck, DeckError, DeckEvent};
+pub use equity::{equity, range_equity, Equity, EquityError, Mode};
+pub use eval::{evaluate, evaluate5, PackedCard, Strength};
+pub use history::{Currency, HandHistory, HistoryError, Player};
+pub use notation::{AnyNotation, CodePoints, Compact, Notated, Notation, Standard, Symbols};
+pub use range::{Combo, Range, RangeError};
+pub use rules::{Ranking, RuleSet};
+pub use showdown::{best_five, showdown, Game, Showdown};
+pub use table::{Action, Event, Street, Table, TableConfig, TableError};
+
+type Cards = Vec<Card>;
+
+// Jokers in a deck, when they are used
+const MAX_JOKERS: usize = 2;
 
 /// Given a list of poker hands, return a list of those hands which win.
 ///
 /// Note the type signature: this function should return _the same_ reference to
 /// the winning hand(s) as were passed in, not reconstructed strings which happen to be equal.
+///
+/// Panics if any of the hands is malformed, use `try_winning_hands` for untrusted input.
 pub fn winning_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
-    let mut winners = vec![];
-    hands.iter().fold(&"_", |prev, curr: &&str| {
-        let (mut hand_1, mut hand_2) = (hand![prev], hand![curr]);
-
-        hand_1.sort();
-        hand_2.sort();
-
-        match hand_1.cmp(&hand_2) {
-            Ordering::Less => {
-                winners.clear();
-                winners.push(*curr);
-                curr
+    try_winning_hands(hands).unwrap_or_else(|err| panic!("invalid poker hand: {err}"))
+}
+
+/// Like `winning_hands`, but rejects malformed hands with the reason they could not be parsed.
+pub fn try_winning_hands<'a>(hands: &[&'a str]) -> Result<Vec<&'a str>, ParseError> {
+    let ranks = hands
+        .iter()
+        .map(|hand| Ok(Hand::parse(hand)?.strength()))
+        .collect::<Result<Vec<Strength>, ParseError>>()?;
+    let best = ranks.iter().max();
+
+    Ok(hands
+        .iter()
+        .zip(&ranks)
+        .filter(|(_, rank)| Some(*rank) == best)
+        .map(|(hand, _)| *hand)
+        .collect())
+}
+
+/// A five card poker hand which can be ranked and compared outside of `winning_hands`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    cards: Cards,
+}
+
+impl Hand {
+    /// Parses a whitespace separated hand such as `"4S 5S 7H 8D JC"`.
+    pub fn parse(hand: &str) -> Result<Hand, ParseError> {
+        let cards = hand
+            .split_whitespace()
+            .map(Card::try_from)
+            .collect::<Result<Cards, ParseError>>()?;
+
+        Hand::from_cards(&cards)
+    }
+
+    /// Builds a hand from exactly five distinct cards, jokers only play through a `RuleSet`.
+    pub fn from_cards(cards: &[Card]) -> Result<Hand, ParseError> {
+        if cards.len() != 5 {
+            return Err(ParseError::WrongHandSize(cards.len()));
+        }
+
+        if cards.iter().any(Card::is_joker) {
+            return Err(ParseError::UnexpectedJoker);
+        }
+
+        if let Some(card) =
+            (1..cards.len()).find_map(|i| cards[..i].iter().find(|&c| *c == cards[i]))
+        {
+            return Err(ParseError::DuplicateCard(card.to_string()));
+        }
+
+        Ok(Hand::sorted(cards.to_vec()))
+    }
+
+    // Builds a hand without validating the cards
+    fn sorted(mut cards: Cards) -> Hand {
+        SortedHand::sort(&mut cards);
+        Hand { cards }
+    }
+
+    /// The cards of the hand, highest value first.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Ranks the hand, naming its category and every card value that breaks a tie.
+    pub fn rank(&self) -> HandRank {
+        self.cards.rank()
+    }
+
+    /// Evaluates the hand with the lookup tables, which is much faster than `rank`
+    /// when only comparing hands.
+    pub fn strength(&self) -> Strength {
+        evaluate5(self.packed())
+    }
+
+    pub(crate) fn packed(&self) -> [PackedCard; 5] {
+        [0, 1, 2, 3, 4].map(|i| PackedCard::from(&self.cards[i]))
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards = self.cards.iter().map(Card::to_string).collect::<Vec<_>>();
+        write!(f, "{}", cards.join(" "))
+    }
+}
+
+/// Errors that can occur while parsing a card or a hand, holding the offending card where there is one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    BadRank(String),
+    BadValue(i32),
+    BadSuit(String),
+    WrongHandSize(usize),
+    DuplicateCard(String),
+    UnexpectedJoker,
+    NotInDeck(String),
+    TooManyJokers(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::BadRank(card) => {
+                write!(
+                    f,
+                    "card `{card}` has an unknown rank, expected 2-10, J, Q, K or A"
+                )
             }
-            Ordering::Equal => {
-                winners.push(*curr);
-                curr
+            ParseError::BadValue(value) => {
+                write!(
+                    f,
+                    "value {value} is not a card, expected 2-14 or 0 for a joker"
+                )
             }
-            Ordering::Greater => {
-                winners.clear();
-                winners.push(*prev);
-                prev
+            ParseError::BadSuit(card) => {
+                write!(
+                    f,
+                    "card `{card}` has an unknown suit, expected S, H, D or C"
+                )
+            }
+            ParseError::WrongHandSize(n) => write!(f, "a hand needs 5 cards, found {n}"),
+            ParseError::DuplicateCard(card) => write!(f, "card `{card}` appears more than once"),
+            ParseError::UnexpectedJoker => write!(f, "jokers are only allowed when they are wild"),
+            ParseError::NotInDeck(card) => write!(f, "card `{card}` is not in the deck"),
+            ParseError::TooManyJokers(n) => {
+                write!(f, "the deck holds {MAX_JOKERS} jokers, found {n}")
             }
         }
-    });
-    winners
+    }
 }
 
-#[derive(PartialEq, Eq, Clone)]
-struct Card {
-    value: i32,
-    card_type: CardTypes,
+impl std::error::Error for ParseError {}
+
+/// Category of a ranked hand, from weakest to strongest.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Category {
+    HighCard,
+    Pair,
+    TwoPairs,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    /// Only possible with wild cards.
+    FiveOfAKind,
 }
-const EMPTY_CARD: Card = Card {
-    value: 0,
-    card_type: CardTypes::None,
-};
-
-impl PartialOrd for Card {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.value.partial_cmp(&other.value)
-    }
+
+/// The rank of a hand: its category followed by the card values that break ties,
+/// grouped cards first (e.g. the trips then the pair of a full house) and then by value.
+/// An ace starting a straight counts as 1.
+///
+/// Ranks are totally ordered: first by category, then kicker by kicker.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct HandRank {
+    category: Category,
+    kickers: Vec<i32>,
 }
 
-impl From<&str> for Card {
-    fn from(value: &str) -> Self {
-        let mut value = value.chars().rev();
-        let (card_type, card) = (value.next().unwrap(), value.rev().collect::<String>());
+impl HandRank {
+    pub fn category(&self) -> Category {
+        self.category
+    }
 
-        let card_type = match card_type {
-            'S' => CardTypes::Spades,
-            'H' => CardTypes::Hearts,
-            'D' => CardTypes::Diamonds,
-            'C' => CardTypes::Clovers,
-            _ => CardTypes::None,
-        };
+    pub fn kickers(&self) -> &[i32] {
+        &self.kickers
+    }
+}
 
-        let value = match card.as_str() {
-            "A" => 14,
-            "K" => 13,
-            "Q" => 12,
-            "J" => 11,
-            c if c.parse::<i32>().is_ok() => c.parse::<i32>().unwrap(),
-            _ => 0,
-        };
+// Describes the hand the way it would be announced at a table, e.g. "Full house, kings over sevens"
+impl fmt::Display for HandRank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let k = &self.kickers;
+        match self.category {
+            Category::HighCard => write!(f, "High card, {}", rank_name(k[0])),
+            Category::Pair => write!(f, "Pair of {}", rank_plural(k[0])),
+            Category::TwoPairs => {
+                write!(
+                    f,
+                    "Two pairs, {} and {}",
+                    rank_plural(k[0]),
+                    rank_plural(k[1])
+                )
+            }
+            Category::ThreeOfAKind => write!(f, "Three of a kind, {}", rank_plural(k[0])),
+            Category::Straight => write!(f, "Straight, {} high", rank_name(k[0])),
+            Category::Flush => write!(f, "Flush, {} high", rank_name(k[0])),
+            Category::FullHouse => {
+                write!(
+                    f,
+                    "Full house, {} over {}",
+                    rank_plural(k[0]),
+                    rank_plural(k[1])
+                )
+            }
+            Category::FourOfAKind => write!(f, "Four of a kind, {}", rank_plural(k[0])),
+            Category::StraightFlush if k[0] == 14 => write!(f, "Royal flush"),
+            Category::StraightFlush => write!(f, "Straight flush, {} high", rank_name(k[0])),
+            Category::FiveOfAKind => write!(f, "Five of a kind, {}", rank_plural(k[0])),
+        }
+    }
+}
 
-        Self { value, card_type }
+// Name of a card value as it is spoken
+fn rank_name(value: i32) -> &'static str {
+    match value {
+        14 | 1 => "ace",
+        13 => "king",
+        12 => "queen",
+        11 => "jack",
+        10 => "ten",
+        9 => "nine",
+        8 => "eight",
+        7 => "seven",
+        6 => "six",
+        5 => "five",
+        4 => "four",
+        3 => "three",
+        _ => "two",
     }
 }
 
-// Definition of card types: Spades, Hearts, Diamonds, Clovers
-#[derive(PartialEq, Eq, Clone, Copy)]
-enum CardTypes {
-    Spades,
-    Hearts,
-    Diamonds,
-    Clovers,
-    None,
+fn rank_plural(value: i32) -> String {
+    match rank_name(value) {
+        "six" => "sixes".into(),
+        name => format!("{name}s"),
+    }
 }
 
-// Definition of poker hand rankings
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-enum Rankings {
-    HighCard(i32),
-    Pair(i32),
-    TwoPairs(i32),
-    ThreeOfAKind(i32),
-    Straight(i32),
-    Flush,
-    FullHouse(i32),
-    FourOfAKind(i32),
-    StraightFlush(i32),
+/// A playing card with a value from 2 to 14 (ace) and a suit, or a joker.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Card {
+    value: i32,
+    card_type: CardTypes,
 }
 
-impl Rankings {
-    //Ranks the Poker Rankings numerically for easy comparisons keeping track of the highest card
-    fn rank(&self) -> (i32, i32) {
-        match self {
-            Rankings::StraightFlush(n) => (9, *n),
-            Rankings::FourOfAKind(n) => (8, *n),
-            Rankings::FullHouse(n) => (7, *n),
-            Rankings::Flush => (6, 0),
-            Rankings::Straight(n) => (5, *n),
-            Rankings::ThreeOfAKind(n) => (4, *n),
-            Rankings::TwoPairs(n) => (3, *n),
-            Rankings::Pair(n) => (2, *n),
-            Rankings::HighCard(n) => (1, *n),
+impl Card {
+    /// A card of a suit with a value from 2 to 14, or the joker with a value of 0.
+    pub fn new(value: i32, card_type: CardTypes) -> Result<Self, ParseError> {
+        match (value, card_type) {
+            (0, CardTypes::Joker) => Ok(Card::joker()),
+            (2..=14, card_type) if card_type != CardTypes::Joker => Ok(Self { value, card_type }),
+            _ => Err(ParseError::BadValue(value)),
+        }
+    }
+
+    /// A joker, which has no value and only plays as a wild card.
+    pub fn joker() -> Self {
+        Self {
+            value: 0,
+            card_type: CardTypes::Joker,
         }
     }
+
+    pub fn is_joker(&self) -> bool {
+        self.card_type == CardTypes::Joker
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn card_type(&self) -> CardTypes {
+        self.card_type
+    }
 }
 
-// PartialOrd implementation for Rankings, allowing Rankings to be compared
-impl PartialOrd for Rankings {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.rank().partial_cmp(&other.rank())
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_joker() {
+            return write!(f, "JK");
+        }
+
+        let value = match self.value {
+            14 | 1 => "A".to_string(),
+            13 => "K".to_string(),
+            12 => "Q".to_string(),
+            11 => "J".to_string(),
+            v => v.to_string(),
+        };
+        let card_type = match self.card_type {
+            CardTypes::Spades => "S",
+            CardTypes::Hearts => "H",
+            CardTypes::Diamonds => "D",
+            CardTypes::Clovers => "C",
+            CardTypes::Joker => unreachable!(),
+        };
+        write!(f, "{value}{card_type}")
     }
 }
 
-// Implement conversion from a Hand to a Ranking
-impl From<&Hand> for Rankings {
-    fn from(hand: &Hand) -> Self {
-        let mut hand = hand.clone();
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
 
-        match (hand.is_flush(), hand.is_straight(), hand.get_pairs()) {
-            (true, true, _) => Rankings::StraightFlush(hand[0].value),
-            (false, false, pairs) if pairs[0].1 == 4 => Rankings::FourOfAKind(pairs[0].0),
-            (false, false, pairs) if pairs[0].1 == 3 && pairs[1].1 == 2 => {
-                Rankings::FullHouse(pairs[0].1)
-            }
-            (true, false, _) => Rankings::Flush,
-            (false, true, _) => Rankings::Straight(hand[0].value),
-            (false, false, pairs) if pairs[0].1 == 3 => Rankings::ThreeOfAKind(pairs[0].0),
-            (false, false, pairs) if pairs[0].1 == 2 && pairs[1].1 == 2 => {
-                Rankings::TwoPairs(pairs[0].0)
-            }
-            (false, false, pairs) if pairs[0].1 == 2 => Rankings::Pair(pairs[0].0),
-            (_, _, _) => Rankings::HighCard(hand[0].value),
+    fn try_from(card: &str) -> Result<Self, Self::Error> {
+        if card == "JK" {
+            return Ok(Card::joker());
         }
+
+        let mut chars = card.chars().rev();
+        let (card_type, value) = (chars.next(), chars.rev().collect::<String>());
+
+        let card_type = match card_type {
+            Some('S') => CardTypes::Spades,
+            Some('H') => CardTypes::Hearts,
+            Some('D') => CardTypes::Diamonds,
+            Some('C') => CardTypes::Clovers,
+            _ => return Err(ParseError::BadSuit(card.to_string())),
+        };
+
+        let value = match value.as_str() {
+            "A" => 14,
+            "K" => 13,
+            "Q" => 12,
+            "J" => 11,
+            v => match v.parse::<i32>() {
+                Ok(v) if (2..=10).contains(&v) => v,
+                _ => return Err(ParseError::BadRank(card.to_string())),
+            },
+        };
+
+        Ok(Self { value, card_type })
     }
 }
 
+// Definition of card types: Spades, Hearts, Diamonds, Clovers and the Joker which has no suit
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum CardTypes {
+    Spades,
+    Hearts,
+    Diamonds,
+    Clovers,
+    Joker,
+}
+
 // Definition of the SortedHand trait
 trait SortedHand {
-    fn is_straight(&mut self) -> bool;
+    fn sort(&mut self);
     fn is_flush(&self) -> bool;
+    fn straight_high(&self) -> Option<i32>;
     fn get_pairs(&self) -> Vec<(i32, i32)>;
-    fn cmp(&self, other: &Self) -> Ordering;
-    fn get_vals(&self) -> Vec<i32>;
-    fn sort(&mut self);
+    fn rank(&self) -> HandRank;
 }
 
-// Implementation of SortedHand for Hand
-impl SortedHand for Hand {
+// Implementation of SortedHand for a list of cards, the other methods expect the cards to be sorted
+impl SortedHand for Cards {
     // Sort the hand by card value
     fn sort(&mut self) {
-        self.sort_by(|a, b| b.partial_cmp(a).unwrap());
-    }
-
-    // Compare two hands
-    fn cmp(&self, other: &Self) -> Ordering {
-        let rank = Rankings::from(self);
-        match rank.partial_cmp(&Rankings::from(other)).unwrap() {
-            // Rankings resulted from pairs get compared by pairs, others get compared by card values
-            Ordering::Equal => match rank {
-                Rankings::Pair(_)
-                | Rankings::TwoPairs(_)
-                | Rankings::ThreeOfAKind(_)
-                | Rankings::FullHouse(_)
-                | Rankings::FourOfAKind(_) => self.get_pairs().cmp(&other.get_pairs()),
-                _ => self.get_vals().cmp(&other.get_vals()),
-            },
-            ord => ord,
-        }
+        self.sort_by_key(|card| Reverse(card.value));
     }
 
     // Check if a hand is a flush
     fn is_flush(&self) -> bool {
         let head_type = self[0].card_type;
 
-        head_type != CardTypes::None && !self.iter().any(|card| card.card_type != head_type)
+        self.len() == 5 && !self.iter().any(|card| card.card_type != head_type)
     }
 
-    // Check if a hand is a straight
-    fn is_straight(&mut self) -> bool {
-        if self[0].card_type == CardTypes::None {
-            return false;
-        }
-        // If card is an ace, then tests ace with value as 1
-        if self[0].value == 14 {
-            let mut other = self.clone();
-            let taken = other.remove(0);
-            other.push(Card {
-                value: 1,
-                card_type: taken.card_type,
-            });
-
-            if other.is_straight() {
-                self.remove(0);
-                self.push(Card {
-                    value: 1,
-                    card_type: taken.card_type,
-                });
-                return true;
+    // Get the highest card of a straight, an ace starting a straight counts as 1 making 5 the highest
+    fn straight_high(&self) -> Option<i32> {
+        let values = self.iter().map(|card| card.value).collect::<Vec<i32>>();
+
+        match values.as_slice() {
+            [14, 5, 4, 3, 2] => Some(5),
+            [high, ..] if values.len() == 5 && values.windows(2).all(|w| w[0] == w[1] + 1) => {
+                Some(*high)
             }
+            _ => None,
         }
-
-        let mut s_iter = self.iter().rev();
-        let mut range = (s_iter.next().unwrap_or(&EMPTY_CARD).value
-            ..=s_iter.last().unwrap_or(&EMPTY_CARD).value)
-            .rev();
-
-        !self
-            .iter()
-            .any(|card| range.next().unwrap_or(0) != card.value)
     }
 
-    // Get the pairs in a hand
+    // Get the pairs in a hand as (value, count), most frequent first then highest value first
     fn get_pairs(&self) -> Vec<(i32, i32)> {
         let mut freq_map: HashMap<i32, i32> = HashMap::new();
 
@@ -250,8 +426,28 @@ impl SortedHand for Hand {
         freq_vec
     }
 
-    // Get the values of the cards in a hand
-    fn get_vals(&self) -> Vec<i32> {
-        self.iter().map(|card| card.value).collect()
+    // Rank the hand, the kickers of a straight count down from its highest card
+    fn rank(&self) -> HandRank {
+        let pairs = self.get_pairs();
+        let counts = pairs.iter().map(|(_, count)| *count).collect::<Vec<i32>>();
+        let kickers = pairs.iter().map(|(value, _)| *value).collect::<Vec<i32>>();
+        let straight = self
+            .straight_high()
+            .map(|high| (high - 4..=high).rev().collect::<Vec<i32>>());
+
+        let (category, kickers) = match (self.is_flush(), straight, counts.as_slice()) {
+            (_, _, [5]) => (Category::FiveOfAKind, kickers),
+            (true, Some(straight), _) => (Category::StraightFlush, straight),
+            (_, _, [4, ..]) => (Category::FourOfAKind, kickers),
+            (_, _, [3, 2]) => (Category::FullHouse, kickers),
+            (true, None, _) => (Category::Flush, kickers),
+            (false, Some(straight), _) => (Category::Straight, straight),
+            (_, _, [3, ..]) => (Category::ThreeOfAKind, kickers),
+            (_, _, [2, 2, ..]) => (Category::TwoPairs, kickers),
+            (_, _, [2, ..]) => (Category::Pair, kickers),
+            _ => (Category::HighCard, kickers),
+        };
+
+        HandRank { category, kickers }
     }
 }
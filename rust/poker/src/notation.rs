@@ -0,0 +1,297 @@
+// Ways of writing cards down. `Standard` is what `Card::try_from` and `Display` use, the others
+// come from poker sites and fonts and are picked with `Card::parse_with` and `Card::display_with`.
+
+use std::fmt;
+
+use crate::{Card, CardTypes, Hand, ParseError};
+
+/// A way of writing cards, implement it to read and write cards in a format of your own.
+pub trait Notation {
+    fn parse_card(&self, card: &str) -> Result<Card, ParseError>;
+
+    fn format_card(&self, card: &Card) -> String;
+
+    /// Parses cards separated by whitespace.
+    fn parse_cards(&self, cards: &str) -> Result<Vec<Card>, ParseError> {
+        cards
+            .split_whitespace()
+            .map(|card| self.parse_card(card))
+            .collect()
+    }
+
+    /// Writes cards separated by a space.
+    fn format_cards(&self, cards: &[Card]) -> String {
+        cards
+            .iter()
+            .map(|card| self.format_card(card))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+/// `10S AH JK`, the notation of `Card::try_from` and `Display`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Standard;
+
+/// `Ts Ah`, as poker sites write hand histories. Jokers are `JK`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Compact;
+
+/// `T♠ A♥`, the outlined suits `♤♡♢♧` and `10` for ten are read as well. Jokers are `🃏`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Symbols;
+
+/// The playing card characters from U+1F0A1 (`🂡`, the ace of spades) on, the joker is U+1F0CF.
+/// Cards may be written with or without spaces between them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct CodePoints;
+
+/// Reads a card in any of the notations above and writes it in the standard one. Letters may be
+/// in either case, so "TS" and "ts" read like "Ts".
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AnyNotation;
+
+impl Notation for Standard {
+    fn parse_card(&self, card: &str) -> Result<Card, ParseError> {
+        Card::try_from(card)
+    }
+
+    fn format_card(&self, card: &Card) -> String {
+        card.to_string()
+    }
+}
+
+impl Notation for Compact {
+    fn parse_card(&self, card: &str) -> Result<Card, ParseError> {
+        if card == "JK" {
+            return Ok(Card::joker());
+        }
+
+        let (value, suit) = split_suit(card)?;
+        let card_type = match suit {
+            's' => CardTypes::Spades,
+            'h' => CardTypes::Hearts,
+            'd' => CardTypes::Diamonds,
+            'c' => CardTypes::Clovers,
+            _ => return Err(ParseError::BadSuit(card.to_string())),
+        };
+        parse_value(value, false).map_or(Err(ParseError::BadRank(card.to_string())), |value| {
+            Ok(Card { value, card_type })
+        })
+    }
+
+    fn format_card(&self, card: &Card) -> String {
+        if card.is_joker() {
+            return "JK".to_string();
+        }
+
+        let suit = match card.card_type() {
+            CardTypes::Spades => 's',
+            CardTypes::Hearts => 'h',
+            CardTypes::Diamonds => 'd',
+            _ => 'c',
+        };
+        format!("{}{suit}", value_char(card.value()))
+    }
+}
+
+impl Notation for Symbols {
+    fn parse_card(&self, card: &str) -> Result<Card, ParseError> {
+        if card == "🃏" {
+            return Ok(Card::joker());
+        }
+
+        let (value, suit) = split_suit(card)?;
+        let card_type = match suit {
+            '♠' | '♤' => CardTypes::Spades,
+            '♥' | '♡' => CardTypes::Hearts,
+            '♦' | '♢' => CardTypes::Diamonds,
+            '♣' | '♧' => CardTypes::Clovers,
+            _ => return Err(ParseError::BadSuit(card.to_string())),
+        };
+        parse_value(value, true).map_or(Err(ParseError::BadRank(card.to_string())), |value| {
+            Ok(Card { value, card_type })
+        })
+    }
+
+    fn format_card(&self, card: &Card) -> String {
+        if card.is_joker() {
+            return "🃏".to_string();
+        }
+
+        let suit = match card.card_type() {
+            CardTypes::Spades => '♠',
+            CardTypes::Hearts => '♥',
+            CardTypes::Diamonds => '♦',
+            _ => '♣',
+        };
+        format!("{}{suit}", value_char(card.value()))
+    }
+}
+
+// The playing cards block has a row of 16 code points per suit: the ace at 1, two to ten at
+// their value, then the jack, the knight which isn't part of a 52 card deck, queen and king
+const JOKER: u32 = 0x1F0CF;
+
+impl Notation for CodePoints {
+    fn parse_card(&self, card: &str) -> Result<Card, ParseError> {
+        let mut chars = card.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => c as u32,
+            _ => return Err(ParseError::BadSuit(card.to_string())),
+        };
+        if code == JOKER {
+            return Ok(Card::joker());
+        }
+
+        let card_type = match code & !0xF {
+            0x1F0A0 => CardTypes::Spades,
+            0x1F0B0 => CardTypes::Hearts,
+            0x1F0C0 => CardTypes::Diamonds,
+            0x1F0D0 => CardTypes::Clovers,
+            _ => return Err(ParseError::BadSuit(card.to_string())),
+        };
+        let value = match code & 0xF {
+            1 => 14,
+            v @ 2..=11 => v as i32,
+            13 => 12,
+            14 => 13,
+            _ => return Err(ParseError::BadRank(card.to_string())),
+        };
+        Ok(Card { value, card_type })
+    }
+
+    fn format_card(&self, card: &Card) -> String {
+        let code = match card.card_type() {
+            CardTypes::Joker => JOKER,
+            card_type => {
+                let suit = match card_type {
+                    CardTypes::Spades => 0x1F0A0,
+                    CardTypes::Hearts => 0x1F0B0,
+                    CardTypes::Diamonds => 0x1F0C0,
+                    _ => 0x1F0D0,
+                };
+                let value = match card.value() {
+                    14 | 1 => 1,
+                    12 => 13,
+                    13 => 14,
+                    v => v as u32,
+                };
+                suit + value
+            }
+        };
+        char::from_u32(code).map_or(String::new(), String::from)
+    }
+
+    fn parse_cards(&self, cards: &str) -> Result<Vec<Card>, ParseError> {
+        cards
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| self.parse_card(c.encode_utf8(&mut [0; 4])))
+            .collect()
+    }
+}
+
+impl Notation for AnyNotation {
+    fn parse_card(&self, card: &str) -> Result<Card, ParseError> {
+        // The standard error is the most helpful one when nothing matches
+        Standard
+            .parse_card(card)
+            .or_else(|err| Compact.parse_card(&compact_case(card)).map_err(|_| err))
+            .or_else(|err| Symbols.parse_card(card).map_err(|_| err))
+            .or_else(|err| CodePoints.parse_card(card).map_err(|_| err))
+    }
+
+    fn format_card(&self, card: &Card) -> String {
+        card.to_string()
+    }
+}
+
+/// A card or cards written in a notation, from `Card::display_with` and `Hand::display_with`.
+pub struct Notated<'a, N: ?Sized> {
+    cards: &'a [Card],
+    notation: &'a N,
+}
+
+impl<N: Notation + ?Sized> fmt::Display for Notated<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.notation.format_cards(self.cards))
+    }
+}
+
+impl Card {
+    /// Reads a card written in `notation`.
+    pub fn parse_with<N: Notation + ?Sized>(card: &str, notation: &N) -> Result<Card, ParseError> {
+        notation.parse_card(card)
+    }
+
+    /// Writes the card in `notation` when displayed.
+    pub fn display_with<'a, N: Notation + ?Sized>(&'a self, notation: &'a N) -> Notated<'a, N> {
+        Notated {
+            cards: std::slice::from_ref(self),
+            notation,
+        }
+    }
+}
+
+impl Hand {
+    /// Reads a hand of five cards written in `notation`.
+    pub fn parse_with<N: Notation + ?Sized>(hand: &str, notation: &N) -> Result<Hand, ParseError> {
+        Hand::from_cards(&notation.parse_cards(hand)?)
+    }
+
+    /// Writes the cards of the hand, highest value first, in `notation` when displayed.
+    pub fn display_with<'a, N: Notation + ?Sized>(&'a self, notation: &'a N) -> Notated<'a, N> {
+        Notated {
+            cards: self.cards(),
+            notation,
+        }
+    }
+}
+
+// Writes a card in the case of the compact notation, an upper case value and a lower case suit
+fn compact_case(card: &str) -> String {
+    match split_suit(card) {
+        Ok((value, suit)) => format!(
+            "{}{}",
+            value.to_ascii_uppercase(),
+            suit.to_ascii_lowercase()
+        ),
+        Err(_) => card.to_string(),
+    }
+}
+
+// Splits the suit, the last character, from the value
+fn split_suit(card: &str) -> Result<(&str, char), ParseError> {
+    let suit = card
+        .chars()
+        .next_back()
+        .ok_or(ParseError::BadRank(card.to_string()))?;
+    Ok((&card[..card.len() - suit.len_utf8()], suit))
+}
+
+fn parse_value(value: &str, ten_as_number: bool) -> Option<i32> {
+    match value {
+        "A" => Some(14),
+        "K" => Some(13),
+        "Q" => Some(12),
+        "J" => Some(11),
+        "T" => Some(10),
+        "10" if ten_as_number => Some(10),
+        v => match v.parse::<i32>() {
+            Ok(v) if (2..=9).contains(&v) && v.to_string() == value => Some(v),
+            _ => None,
+        },
+    }
+}
+
+fn value_char(value: i32) -> char {
+    match value {
+        14 | 1 => 'A',
+        13 => 'K',
+        12 => 'Q',
+        11 => 'J',
+        10 => 'T',
+        v => char::from_digit(v as u32, 10).unwrap_or('?'),
+    }
+}
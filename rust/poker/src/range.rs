@@ -0,0 +1,288 @@
+// Hold'em hand ranges written the way players write them, e.g. `AKs, TT+, 98s-65s, AhKh:0.5`.
+//
+//     TT        a pair, 6 combos          TT+  TT-77   pairs up from or between
+//     AKs AKo   suited, 4 combos or       AK           both, 16 combos
+//               offsuit, 12 combos
+//     ATs+      the kicker up to one      A9s-A2s      the kicker between
+//               below the first card      98s-65s      connectors keeping the gap
+//     AhKh      one combo                 :0.5         a weight from 0 to 1 for the item
+//
+// A combo listed again takes the weight of the last item listing it.
+
+use std::{fmt, str::FromStr};
+
+use crate::{Card, CardTypes, Compact, Notation};
+
+const RANKS: &str = "23456789TJQKA";
+const SUITS: [CardTypes; 4] = [
+    CardTypes::Spades,
+    CardTypes::Hearts,
+    CardTypes::Diamonds,
+    CardTypes::Clovers,
+];
+
+/// Two hole cards and how often a range holds them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Combo {
+    cards: [Card; 2],
+    weight: f64,
+}
+
+impl Combo {
+    pub fn cards(&self) -> &[Card; 2] {
+        &self.cards
+    }
+
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    pub fn blocks(&self, cards: &[Card]) -> bool {
+        self.cards.iter().any(|card| cards.contains(card))
+    }
+}
+
+/// The hole cards a player may hold, each with a weight.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Range {
+    combos: Vec<Combo>,
+}
+
+/// Reasons a range can't be read, holding the offending item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    BadItem(String),
+    BadWeight(String),
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::BadItem(item) => write!(f, "`{item}` is not a hand range"),
+            RangeError::BadWeight(item) => write!(f, "`{item}` has a weight outside 0 to 1"),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+impl Range {
+    /// Parses a comma separated range such as `"AKs, TT+, 98s-65s"`.
+    pub fn parse(range: &str) -> Result<Range, RangeError> {
+        let mut parsed = Range::default();
+
+        for item in range
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            let (hands, weight) = match item.split_once(':') {
+                Some((hands, weight)) => match weight.trim().parse::<f64>() {
+                    Ok(weight) if (0.0..=1.0).contains(&weight) => (hands.trim(), weight),
+                    _ => return Err(RangeError::BadWeight(item.to_string())),
+                },
+                None => (item, 1.0),
+            };
+
+            let cards = parse_item(hands).ok_or(RangeError::BadItem(item.to_string()))?;
+            for cards in cards {
+                parsed.insert(cards, weight);
+            }
+        }
+
+        // A zero weight is how an item is taken back out
+        parsed.combos.retain(|combo| combo.weight > 0.0);
+        Ok(parsed)
+    }
+
+    /// A range holding exactly the given hole cards.
+    pub fn from_cards(cards: [Card; 2]) -> Range {
+        Range {
+            combos: vec![Combo { cards, weight: 1.0 }],
+        }
+    }
+
+    pub fn combos(&self) -> &[Combo] {
+        &self.combos
+    }
+
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// The number of combos counting each by its weight.
+    pub fn weighted_len(&self) -> f64 {
+        self.combos.iter().map(Combo::weight).sum()
+    }
+
+    /// The range left once `cards` are known to be elsewhere, on the board or in another hand.
+    pub fn without(&self, cards: &[Card]) -> Range {
+        Range {
+            combos: self
+                .combos
+                .iter()
+                .filter(|combo| !combo.blocks(cards))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn insert(&mut self, cards: [Card; 2], weight: f64) {
+        let same = |combo: &&mut Combo| cards.iter().all(|card| combo.cards.contains(card));
+        match self.combos.iter_mut().find(same) {
+            Some(combo) => combo.weight = weight,
+            None => self.combos.push(Combo { cards, weight }),
+        }
+    }
+}
+
+impl FromStr for Range {
+    type Err = RangeError;
+
+    fn from_str(range: &str) -> Result<Self, Self::Err> {
+        Range::parse(range)
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let combos = self
+            .combos
+            .iter()
+            .map(|combo| match combo.weight {
+                1.0 => Compact.format_cards(&combo.cards).replace(' ', ""),
+                weight => format!(
+                    "{}:{weight}",
+                    Compact.format_cards(&combo.cards).replace(' ', "")
+                ),
+            })
+            .collect::<Vec<String>>();
+        write!(f, "{}", combos.join(", "))
+    }
+}
+
+// Which suits a two rank item covers
+#[derive(Clone, Copy, PartialEq)]
+enum Suits {
+    Suited,
+    Offsuit,
+    Any,
+}
+
+// A pair or two ranks with their suits, such as `TT` or `AKs`
+#[derive(Clone, Copy, PartialEq)]
+struct Hands {
+    high: i32,
+    low: i32,
+    suits: Suits,
+}
+
+fn parse_item(item: &str) -> Option<Vec<[Card; 2]>> {
+    if let Some(cards) = parse_combo(item) {
+        return Some(vec![cards]);
+    }
+
+    let hands = if let Some(hands) = item.strip_suffix('+') {
+        let from = parse_hands(hands)?;
+        match from.high == from.low {
+            true => (from.high..=14).map(|v| pair(v, from.suits)).collect(),
+            false => (from.low..from.high)
+                .map(|low| Hands { low, ..from })
+                .collect(),
+        }
+    } else if let Some((first, last)) = item.split_once('-') {
+        let (first, last) = (parse_hands(first)?, parse_hands(last)?);
+        let (top, bottom) = match first.high >= last.high {
+            true => (first, last),
+            false => (last, first),
+        };
+        let gap = top.high - top.low;
+        if top.suits != bottom.suits {
+            return None;
+        } else if top.high == top.low && bottom.high == bottom.low {
+            (bottom.high..=top.high)
+                .map(|v| pair(v, top.suits))
+                .collect()
+        } else if top.high == bottom.high && bottom.low < top.low {
+            (bottom.low..=top.low)
+                .map(|low| Hands { low, ..top })
+                .collect()
+        } else if gap > 0 && bottom.high - bottom.low == gap {
+            (bottom.high..=top.high)
+                .map(|high| Hands {
+                    high,
+                    low: high - gap,
+                    suits: top.suits,
+                })
+                .collect()
+        } else {
+            return None;
+        }
+    } else {
+        vec![parse_hands(item)?]
+    };
+
+    Some(hands.into_iter().flat_map(combos).collect())
+}
+
+fn pair(value: i32, suits: Suits) -> Hands {
+    Hands {
+        high: value,
+        low: value,
+        suits,
+    }
+}
+
+// `AhKh`, a single combo in compact notation
+fn parse_combo(item: &str) -> Option<[Card; 2]> {
+    let split = item.char_indices().nth(2)?.0;
+    let first = Compact.parse_card(&item[..split]).ok()?;
+    let second = Compact.parse_card(&item[split..]).ok()?;
+    (first != second && !first.is_joker() && !second.is_joker()).then_some([first, second])
+}
+
+// `TT`, `AKs`, `AKo` or `AK`, the higher rank first
+fn parse_hands(hands: &str) -> Option<Hands> {
+    let mut chars = hands.chars();
+    let value = |c: Option<char>| c.and_then(|c| RANKS.find(c)).map(|i| i as i32 + 2);
+    let (high, low) = (value(chars.next())?, value(chars.next())?);
+    let suits = match (chars.next(), chars.next()) {
+        (None, _) => Suits::Any,
+        (Some('s'), None) if high != low => Suits::Suited,
+        (Some('o'), None) if high != low => Suits::Offsuit,
+        _ => return None,
+    };
+
+    (high >= low).then_some(Hands { high, low, suits })
+}
+
+fn combos(hands: Hands) -> Vec<[Card; 2]> {
+    let mut combos = vec![];
+    for (i, &first) in SUITS.iter().enumerate() {
+        for (j, &second) in SUITS.iter().enumerate() {
+            let keep = match (hands.high == hands.low, hands.suits) {
+                (true, _) => i < j,
+                (false, Suits::Suited) => i == j,
+                (false, Suits::Offsuit) => i != j,
+                (false, Suits::Any) => true,
+            };
+            if keep {
+                combos.push([
+                    Card {
+                        value: hands.high,
+                        card_type: first,
+                    },
+                    Card {
+                        value: hands.low,
+                        card_type: second,
+                    },
+                ]);
+            }
+        }
+    }
+    combos
+}
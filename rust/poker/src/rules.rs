@@ -0,0 +1,297 @@
+use std::cmp::Ordering;
+
+use crate::{
+    showdown::combinations, Card, CardTypes, Cards, Category, HandRank, ParseError, SortedHand,
+    MAX_JOKERS,
+};
+
+/// How hands are ordered in a game.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Ranking {
+    /// Standard high hands, an ace can start a straight.
+    High,
+    /// The lowest hand wins, aces are low and straights and flushes don't count (Razz).
+    AceToFive,
+    /// The lowest hand wins, aces are high and straights and flushes count against the hand.
+    DeuceToSeven,
+    /// High hands played with the 36 cards from six up: A-6-7-8-9 is a straight and a flush
+    /// beats a full house.
+    ShortDeck,
+}
+
+/// The rules a hand is scored by: how hands are ordered and which cards are wild.
+/// A wild card plays as whichever card makes the best hand, so it can make five of a kind.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RuleSet {
+    ranking: Ranking,
+    deuces_wild: bool,
+    jokers_wild: bool,
+}
+
+impl RuleSet {
+    pub fn new(ranking: Ranking) -> Self {
+        Self {
+            ranking,
+            deuces_wild: false,
+            jokers_wild: false,
+        }
+    }
+
+    pub fn with_deuces_wild(self) -> Self {
+        Self {
+            deuces_wild: true,
+            ..self
+        }
+    }
+
+    pub fn with_jokers_wild(self) -> Self {
+        Self {
+            jokers_wild: true,
+            ..self
+        }
+    }
+
+    pub fn ranking(&self) -> Ranking {
+        self.ranking
+    }
+
+    pub fn is_wild(&self, card: &Card) -> bool {
+        (self.jokers_wild && card.is_joker()) || (self.deuces_wild && card.value == 2)
+    }
+
+    /// Ranks exactly five cards, wild cards taking the value that makes the best hand.
+    pub fn rank(&self, cards: &[Card]) -> Result<HandRank, ParseError> {
+        if cards.len() != 5 {
+            return Err(ParseError::WrongHandSize(cards.len()));
+        }
+        self.validate(cards)?;
+
+        Ok(self.best_substitution(cards))
+    }
+
+    /// Picks the best five cards out of five or more, e.g. the best low out of seven in Razz.
+    pub fn best_five(&self, cards: &[Card]) -> Result<(Vec<Card>, HandRank), ParseError> {
+        if cards.len() < 5 {
+            return Err(ParseError::WrongHandSize(cards.len()));
+        }
+        self.validate(cards)?;
+
+        let best = combinations(cards, 5)
+            .map(|hand| {
+                let rank = self.best_substitution(&hand);
+                (hand, rank)
+            })
+            .reduce(|best, next| match self.cmp(&next.1, &best.1) {
+                Ordering::Greater => next,
+                _ => best,
+            })
+            .unwrap();
+
+        Ok(best)
+    }
+
+    /// Compares two ranks under these rules, `Greater` when `a` is the better hand.
+    pub fn cmp(&self, a: &HandRank, b: &HandRank) -> Ordering {
+        match self.ranking {
+            Ranking::High => a.cmp(b),
+            Ranking::ShortDeck => (short_deck_order(a.category), &a.kickers)
+                .cmp(&(short_deck_order(b.category), &b.kickers)),
+            Ranking::AceToFive | Ranking::DeuceToSeven => b.cmp(a),
+        }
+    }
+
+    /// Like `try_winning_hands`, scoring the hands by these rules. Jokers are written `JK`.
+    pub fn winning_hands<'a>(&self, hands: &[&'a str]) -> Result<Vec<&'a str>, ParseError> {
+        let ranks = hands
+            .iter()
+            .map(|hand| {
+                let cards = hand
+                    .split_whitespace()
+                    .map(Card::try_from)
+                    .collect::<Result<Cards, ParseError>>()?;
+                self.rank(&cards)
+            })
+            .collect::<Result<Vec<HandRank>, ParseError>>()?;
+        let best = ranks.iter().max_by(|a, b| self.cmp(a, b)).cloned();
+
+        Ok(hands
+            .iter()
+            .zip(&ranks)
+            .filter(|(_, rank)| Some(*rank) == best.as_ref())
+            .map(|(hand, _)| *hand)
+            .collect())
+    }
+
+    // Lowest value in the deck
+    fn lowest(&self) -> i32 {
+        match self.ranking {
+            Ranking::ShortDeck => 6,
+            _ => 2,
+        }
+    }
+
+    // Jokers need to be wild, there are only as many as the deck holds, short deck has no cards
+    // below 6 and only jokers can appear more than once
+    fn validate(&self, cards: &[Card]) -> Result<(), ParseError> {
+        let jokers = cards.iter().filter(|c| c.is_joker()).count();
+        if !self.jokers_wild && jokers > 0 {
+            return Err(ParseError::UnexpectedJoker);
+        }
+        if jokers > MAX_JOKERS {
+            return Err(ParseError::TooManyJokers(jokers));
+        }
+        if let Some(card) = cards
+            .iter()
+            .find(|c| !c.is_joker() && c.value < self.lowest())
+        {
+            return Err(ParseError::NotInDeck(card.to_string()));
+        }
+
+        let duplicate = (1..cards.len())
+            .filter(|&i| !cards[i].is_joker())
+            .find_map(|i| cards[..i].iter().find(|&c| *c == cards[i]));
+        match duplicate {
+            Some(card) => Err(ParseError::DuplicateCard(card.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    // Tries every value for the wild cards. Wild cards are interchangeable, so only the multisets
+    // of values are tried, each with two choices of suits: the suit the other cards share, in case
+    // it makes a flush, and suits which never make one, as a flush is worse in lowball. A flush
+    // can't hold a card twice, so wild cards only take values missing from it.
+    fn best_substitution(&self, cards: &[Card]) -> HandRank {
+        let (wild, natural): (Cards, Cards) = cards.iter().cloned().partition(|c| self.is_wild(c));
+        if wild.is_empty() {
+            return self.rank_natural(natural);
+        }
+
+        let shared = match natural.first() {
+            Some(first) if natural.iter().all(|c| c.card_type == first.card_type) => {
+                Some(first.card_type)
+            }
+            Some(_) => None,
+            None => Some(CardTypes::Spades),
+        };
+        let suited = shared.map(|card_type| vec![card_type; wild.len()]);
+        // Never all the shared suit, and never all alike with more than one wild card
+        let mixed = SUITS
+            .into_iter()
+            .filter(|&card_type| Some(card_type) != shared)
+            .cycle()
+            .take(wild.len())
+            .collect::<Vec<CardTypes>>();
+
+        let mut best: Option<HandRank> = None;
+        let mut values = vec![self.lowest(); wild.len()];
+        loop {
+            for suits in suited.iter().chain([&mixed]) {
+                let mut hand = natural.clone();
+                hand.extend(
+                    values
+                        .iter()
+                        .zip(suits)
+                        .map(|(&value, &card_type)| Card { value, card_type }),
+                );
+                if Some(suits) == suited.as_ref() && has_duplicate(&hand) {
+                    continue;
+                }
+                let rank = self.rank_natural(hand);
+                if best
+                    .as_ref()
+                    .is_none_or(|best| self.cmp(&rank, best) == Ordering::Greater)
+                {
+                    best = Some(rank);
+                }
+            }
+
+            // Next non-decreasing sequence of values
+            match values.iter().rposition(|&value| value < 14) {
+                Some(i) => {
+                    let value = values[i] + 1;
+                    values[i..].iter_mut().for_each(|v| *v = value);
+                }
+                None => return best.unwrap(),
+            }
+        }
+    }
+
+    // Ranks five cards without wild cards
+    fn rank_natural(&self, mut cards: Cards) -> HandRank {
+        SortedHand::sort(&mut cards);
+        let values = cards.iter().map(|card| card.value).collect::<Vec<i32>>();
+
+        match (self.ranking, values.as_slice()) {
+            (Ranking::ShortDeck, [14, 9, 8, 7, 6]) => HandRank {
+                category: match cards.is_flush() {
+                    true => Category::StraightFlush,
+                    false => Category::Straight,
+                },
+                kickers: vec![9, 8, 7, 6, 5],
+            },
+            (Ranking::DeuceToSeven, [14, 5, 4, 3, 2]) => HandRank {
+                category: match cards.is_flush() {
+                    true => Category::Flush,
+                    false => Category::HighCard,
+                },
+                kickers: values,
+            },
+            (Ranking::AceToFive, _) => {
+                cards
+                    .iter_mut()
+                    .filter(|c| c.value == 14)
+                    .for_each(|c| c.value = 1);
+                SortedHand::sort(&mut cards);
+                rank_by_pairs(&cards)
+            }
+            _ => cards.rank(),
+        }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::new(Ranking::High)
+    }
+}
+
+const SUITS: [CardTypes; 4] = [
+    CardTypes::Spades,
+    CardTypes::Hearts,
+    CardTypes::Diamonds,
+    CardTypes::Clovers,
+];
+
+fn has_duplicate(cards: &[Card]) -> bool {
+    (1..cards.len()).any(|i| cards[..i].contains(&cards[i]))
+}
+
+// Ranks a hand only by its pairs, ignoring straights and flushes
+fn rank_by_pairs(cards: &Cards) -> HandRank {
+    let pairs = cards.get_pairs();
+    let counts = pairs.iter().map(|(_, count)| *count).collect::<Vec<i32>>();
+
+    let category = match counts.as_slice() {
+        [5] => Category::FiveOfAKind,
+        [4, ..] => Category::FourOfAKind,
+        [3, 2] => Category::FullHouse,
+        [3, ..] => Category::ThreeOfAKind,
+        [2, 2, ..] => Category::TwoPairs,
+        [2, ..] => Category::Pair,
+        _ => Category::HighCard,
+    };
+
+    HandRank {
+        category,
+        kickers: pairs.into_iter().map(|(value, _)| value).collect(),
+    }
+}
+
+// With fewer cards in the deck a flush is rarer than a full house
+fn short_deck_order(category: Category) -> Category {
+    match category {
+        Category::Flush => Category::FullHouse,
+        Category::FullHouse => Category::Flush,
+        category => category,
+    }
+}
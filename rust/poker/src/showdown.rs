@@ -0,0 +1,132 @@
+use crate::{Card, Hand, HandRank};
+
+/// Picks the best five card hand out of five or more cards, as in Texas Hold'em where
+/// any five of the two hole cards and five board cards can be used.
+///
+/// # Panics
+///
+/// Panics if fewer than five cards are given.
+pub fn best_five(cards: &[Card]) -> (Hand, HandRank) {
+    best_of(combinations(cards, 5))
+}
+
+/// Finds the winners of a Texas Hold'em showdown, see `Game::showdown`.
+pub fn showdown(board: &[Card], players: &[Vec<Card>]) -> Showdown {
+    Game::Holdem.showdown(board, players)
+}
+
+/// The rules deciding which cards a player may combine into their hand.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Game {
+    /// Any five of the hole and board cards.
+    Holdem,
+    /// Exactly two hole cards and three board cards.
+    Omaha,
+}
+
+impl Game {
+    /// The number of hole cards each player is dealt.
+    pub fn hole_cards(&self) -> usize {
+        match self {
+            Game::Holdem => 2,
+            Game::Omaha => 4,
+        }
+    }
+
+    /// Picks the best hand a player can make out of their hole cards and the board.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cards can't make a five card hand under the rules of the game.
+    pub fn best_hand(&self, hole: &[Card], board: &[Card]) -> (Hand, HandRank) {
+        match self {
+            Game::Holdem => best_five(&[hole, board].concat()),
+            Game::Omaha => best_of(combinations(hole, 2).flat_map(|hole| {
+                combinations(board, 3).map(move |board| [hole.as_slice(), &board].concat())
+            })),
+        }
+    }
+
+    /// Ranks the hand of every player and finds the winners, players are identified by
+    /// their index in `players`.
+    pub fn showdown(&self, board: &[Card], players: &[Vec<Card>]) -> Showdown {
+        let hands = players
+            .iter()
+            .map(|hole| self.best_hand(hole, board))
+            .collect::<Vec<(Hand, HandRank)>>();
+        let best = hands.iter().map(|(_, rank)| rank).max();
+        let winners = (0..hands.len())
+            .filter(|&i| Some(&hands[i].1) == best)
+            .collect();
+
+        Showdown { hands, winners }
+    }
+}
+
+/// The outcome of a showdown: the best hand of each player and who won.
+#[derive(Clone, Debug)]
+pub struct Showdown {
+    hands: Vec<(Hand, HandRank)>,
+    winners: Vec<usize>,
+}
+
+impl Showdown {
+    /// The best hand of each player, in the order the players were given.
+    pub fn hands(&self) -> &[(Hand, HandRank)] {
+        &self.hands
+    }
+
+    /// The players holding the best hand, more than one means the pot is split.
+    pub fn winners(&self) -> &[usize] {
+        &self.winners
+    }
+
+    /// Splits a pot between the winners as `(player, amount)`.
+    /// Chips that can't be split evenly go one each to the earliest players.
+    pub fn split_pot(&self, pot: u64) -> Vec<(usize, u64)> {
+        let n = self.winners.len() as u64;
+
+        self.winners
+            .iter()
+            .enumerate()
+            .map(|(i, &player)| (player, pot / n + u64::from((i as u64) < pot % n)))
+            .collect()
+    }
+}
+
+// Picks the highest ranked hand, the first one wins ties
+fn best_of(hands: impl Iterator<Item = Vec<Card>>) -> (Hand, HandRank) {
+    let hand = hands
+        .map(Hand::sorted)
+        .map(|hand| (hand.strength(), hand))
+        .reduce(|best, next| if next.0 > best.0 { next } else { best })
+        .expect("a hand needs at least 5 cards")
+        .1;
+    let rank = hand.rank();
+
+    (hand, rank)
+}
+
+// Every way of choosing k of the cards, keeping their order
+pub(crate) fn combinations(cards: &[Card], k: usize) -> impl Iterator<Item = Vec<Card>> + '_ {
+    let mut indices = (0..k).collect::<Vec<usize>>();
+    let mut done = k > cards.len();
+
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let combination = indices.iter().map(|&i| cards[i].clone()).collect();
+
+        // Advance the rightmost index that still has room to move
+        match (0..k).rev().find(|&i| indices[i] < cards.len() - k + i) {
+            Some(i) => {
+                indices[i] += 1;
+                (i + 1..k).for_each(|j| indices[j] = indices[j - 1] + 1);
+            }
+            None => done = true,
+        }
+
+        Some(combination)
+    })
+}
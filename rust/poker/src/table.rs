@@ -0,0 +1,622 @@
+use std::fmt;
+
+use crate::{best_five, Card, Deck, DeckError, HandRank};
+
+/// Stakes of a table.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TableConfig {
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub ante: u64,
+}
+
+/// A decision of the player to act. Amounts are the player's total bet on the street,
+/// so `Raise(300)` raises to 300.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Bet(u64),
+    Raise(u64),
+    AllIn,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+}
+
+/// Everything that happens at a table, in order. Replaying the `HandStarted` events and the
+/// player decisions through a table with the same stacks rebuilds the exact same log.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Event {
+    HandStarted {
+        hand: u32,
+        button: usize,
+        seed: u64,
+    },
+    Ante {
+        seat: usize,
+        amount: u64,
+    },
+    SmallBlind {
+        seat: usize,
+        amount: u64,
+    },
+    BigBlind {
+        seat: usize,
+        amount: u64,
+    },
+    HoleCards {
+        seat: usize,
+        cards: Vec<Card>,
+    },
+    Fold {
+        seat: usize,
+    },
+    Check {
+        seat: usize,
+    },
+    Call {
+        seat: usize,
+        amount: u64,
+        all_in: bool,
+    },
+    Bet {
+        seat: usize,
+        amount: u64,
+        all_in: bool,
+    },
+    Raise {
+        seat: usize,
+        to: u64,
+        all_in: bool,
+    },
+    Board {
+        street: Street,
+        cards: Vec<Card>,
+    },
+    UncalledBet {
+        seat: usize,
+        amount: u64,
+    },
+    /// `rank` is `None` for cards shown before the board gives five to rank.
+    Showdown {
+        seat: usize,
+        cards: Vec<Card>,
+        rank: Option<HandRank>,
+    },
+    /// Pot 0 is the main pot, the others are side pots.
+    Won {
+        seat: usize,
+        pot: usize,
+        amount: u64,
+    },
+}
+
+/// Reasons a table refuses to start a hand or to take an action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableError {
+    NotEnoughPlayers,
+    HandInProgress,
+    NoHandInProgress,
+    NotYourTurn { seat: usize, to_act: usize },
+    CannotCheck { to_call: u64 },
+    CannotCall,
+    CannotBet,
+    CannotRaise,
+    BetTooSmall { min: u64 },
+    NotEnoughChips { stack: u64 },
+    Deck(DeckError),
+    ReplayMismatch { event: usize },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::NotEnoughPlayers => write!(f, "a hand needs 2 players with chips"),
+            TableError::HandInProgress => write!(f, "a hand is already being played"),
+            TableError::NoHandInProgress => write!(f, "no hand is being played"),
+            TableError::NotYourTurn { seat, to_act } => {
+                write!(f, "seat {seat} can't act, it is seat {to_act}'s turn")
+            }
+            TableError::CannotCheck { to_call } => {
+                write!(f, "can't check facing {to_call} to call")
+            }
+            TableError::CannotCall => write!(f, "there is no bet to call"),
+            TableError::CannotBet => write!(f, "there already is a bet, raise instead"),
+            TableError::CannotRaise => write!(f, "raising is not allowed"),
+            TableError::BetTooSmall { min } => write!(f, "the bet must be at least {min}"),
+            TableError::NotEnoughChips { stack } => write!(f, "only {stack} chips left"),
+            TableError::Deck(err) => write!(f, "{err}"),
+            TableError::ReplayMismatch { event } => write!(f, "replay differs at event {event}"),
+        }
+    }
+}
+
+impl std::error::Error for TableError {}
+
+impl From<DeckError> for TableError {
+    fn from(err: DeckError) -> Self {
+        TableError::Deck(err)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Seat {
+    stack: u64,
+    hole: Vec<Card>,
+    // Chips put in on this street and in the whole hand
+    bet: u64,
+    total: u64,
+    in_hand: bool,
+    folded: bool,
+    acted: bool,
+    // Cleared once the player acted until someone makes a full raise
+    can_raise: bool,
+}
+
+impl Seat {
+    fn can_act(&self) -> bool {
+        self.in_hand && !self.folded && self.stack > 0
+    }
+
+    fn is_live(&self) -> bool {
+        self.in_hand && !self.folded
+    }
+
+    // Moves chips from the stack into the pot, all in if the stack runs out
+    fn put_in(&mut self, amount: u64) -> u64 {
+        let amount = amount.min(self.stack);
+        self.stack -= amount;
+        self.bet += amount;
+        self.total += amount;
+        amount
+    }
+}
+
+/// A no limit Texas Hold'em table: deals hands, validates every decision, moves through the
+/// streets, builds side pots for all in players and pays out the pots using the hand evaluator.
+#[derive(Clone, Debug)]
+pub struct Table {
+    config: TableConfig,
+    seats: Vec<Seat>,
+    hands: u32,
+    button: Option<usize>,
+    deck: Deck,
+    board: Vec<Card>,
+    street: Option<Street>,
+    to_act: Option<usize>,
+    current_bet: u64,
+    min_raise: u64,
+    events: Vec<Event>,
+}
+
+impl Table {
+    /// Seats a player for every stack, players without chips sit out.
+    pub fn new(config: TableConfig, stacks: &[u64]) -> Self {
+        Self {
+            config,
+            seats: stacks
+                .iter()
+                .map(|&stack| Seat {
+                    stack,
+                    ..Seat::default()
+                })
+                .collect(),
+            hands: 0,
+            button: None,
+            deck: Deck::new(),
+            board: vec![],
+            street: None,
+            to_act: None,
+            current_bet: 0,
+            min_raise: 0,
+            events: vec![],
+        }
+    }
+
+    /// Rebuilds a table by replaying a log, failing if the replay doesn't log the same events.
+    pub fn replay(
+        config: TableConfig,
+        stacks: &[u64],
+        events: &[Event],
+    ) -> Result<Self, TableError> {
+        let mut table = Table::new(config, stacks);
+
+        for event in events {
+            match *event {
+                Event::HandStarted { seed, .. } => table.start_hand(seed)?,
+                Event::Fold { seat } => table.act(seat, Action::Fold)?,
+                Event::Check { seat } => table.act(seat, Action::Check)?,
+                Event::Call { seat, .. } => table.act(seat, Action::Call)?,
+                Event::Bet { seat, amount, .. } => table.act(seat, Action::Bet(amount))?,
+                Event::Raise { seat, to, .. } => table.act(seat, Action::Raise(to))?,
+                _ => continue,
+            }
+        }
+
+        match table.events.iter().zip(events).position(|(a, b)| a != b) {
+            Some(event) => Err(TableError::ReplayMismatch { event }),
+            None if table.events.len() != events.len() => Err(TableError::ReplayMismatch {
+                event: table.events.len().min(events.len()),
+            }),
+            None => Ok(table),
+        }
+    }
+
+    /// Moves the button, shuffles a new deck with `seed`, takes the antes and blinds and deals.
+    pub fn start_hand(&mut self, seed: u64) -> Result<(), TableError> {
+        if self.street.is_some() {
+            return Err(TableError::HandInProgress);
+        }
+        let players = self.seats.iter().filter(|seat| seat.stack > 0).count();
+        if players < 2 {
+            return Err(TableError::NotEnoughPlayers);
+        }
+
+        for seat in self.seats.iter_mut() {
+            *seat = Seat {
+                stack: seat.stack,
+                in_hand: seat.stack > 0,
+                can_raise: true,
+                ..Seat::default()
+            };
+        }
+        let button = match self.button {
+            Some(button) => self.next_seat(button, Seat::is_live),
+            None => self.next_seat(self.seats.len() - 1, Seat::is_live),
+        };
+        self.hands += 1;
+        self.button = Some(button);
+        self.deck = Deck::shuffled(seed);
+        self.board.clear();
+        self.street = Some(Street::Preflop);
+        self.events.push(Event::HandStarted {
+            hand: self.hands,
+            button,
+            seed,
+        });
+
+        if self.config.ante > 0 {
+            for seat in self.live_seats_from(button) {
+                let amount = self.seats[seat].put_in(self.config.ante);
+                self.seats[seat].bet = 0;
+                self.events.push(Event::Ante { seat, amount });
+            }
+        }
+
+        // Heads up the button posts the small blind
+        let small_blind = match players {
+            2 => button,
+            _ => self.next_seat(button, Seat::is_live),
+        };
+        let big_blind = self.next_seat(small_blind, Seat::is_live);
+        let amount = self.seats[small_blind].put_in(self.config.small_blind);
+        self.events.push(Event::SmallBlind {
+            seat: small_blind,
+            amount,
+        });
+        let amount = self.seats[big_blind].put_in(self.config.big_blind);
+        self.events.push(Event::BigBlind {
+            seat: big_blind,
+            amount,
+        });
+        self.current_bet = self.config.big_blind;
+        self.min_raise = self.config.big_blind;
+
+        // One card at a time, starting left of the button
+        let order = self.live_seats_from(self.next_seat(button, Seat::is_live));
+        for _ in 0..2 {
+            for &seat in &order {
+                let card = self.deck.deal(1)?;
+                self.seats[seat].hole.extend(card);
+            }
+        }
+        for &seat in &order {
+            self.events.push(Event::HoleCards {
+                seat,
+                cards: self.seats[seat].hole.clone(),
+            });
+        }
+
+        self.to_act = Some(self.next_seat(big_blind, Seat::can_act));
+        self.advance()
+    }
+
+    /// Takes the decision of the player to act.
+    pub fn act(&mut self, seat: usize, action: Action) -> Result<(), TableError> {
+        let to_act = self.to_act.ok_or(TableError::NoHandInProgress)?;
+        if seat != to_act {
+            return Err(TableError::NotYourTurn { seat, to_act });
+        }
+
+        let player = self.seats[seat].clone();
+        let to_call = self.current_bet.saturating_sub(player.bet);
+        let all_in_to = player.bet + player.stack;
+        let action = match action {
+            Action::AllIn if all_in_to <= self.current_bet => Action::Call,
+            Action::AllIn if self.current_bet == 0 => Action::Bet(all_in_to),
+            Action::AllIn => Action::Raise(all_in_to),
+            action => action,
+        };
+
+        let event = match action {
+            Action::Fold => {
+                self.seats[seat].folded = true;
+                Event::Fold { seat }
+            }
+            Action::Check if to_call > 0 => return Err(TableError::CannotCheck { to_call }),
+            Action::Check => Event::Check { seat },
+            Action::Call if to_call == 0 => return Err(TableError::CannotCall),
+            Action::Call => {
+                let amount = self.seats[seat].put_in(to_call);
+                Event::Call {
+                    seat,
+                    amount,
+                    all_in: self.seats[seat].stack == 0,
+                }
+            }
+            Action::Bet(_) if self.current_bet > 0 => return Err(TableError::CannotBet),
+            Action::Bet(amount) | Action::Raise(amount) => {
+                if self.current_bet > 0 && !player.can_raise {
+                    return Err(TableError::CannotRaise);
+                }
+                if amount > all_in_to {
+                    return Err(TableError::NotEnoughChips {
+                        stack: player.stack,
+                    });
+                }
+                let min = match self.current_bet {
+                    0 => self.config.big_blind,
+                    bet => bet + self.min_raise,
+                };
+                // Going all in for less than a full raise is allowed, it just doesn't reopen the betting
+                if amount < min && (amount < all_in_to || amount <= self.current_bet) {
+                    return Err(TableError::BetTooSmall { min });
+                }
+
+                if amount >= min {
+                    self.min_raise = amount - self.current_bet;
+                    self.seats.iter_mut().for_each(|seat| seat.can_raise = true);
+                }
+                let was_bet = self.current_bet == 0;
+                self.current_bet = amount;
+                self.seats[seat].put_in(amount - player.bet);
+                let all_in = self.seats[seat].stack == 0;
+                match was_bet {
+                    true => Event::Bet {
+                        seat,
+                        amount,
+                        all_in,
+                    },
+                    false => Event::Raise {
+                        seat,
+                        to: amount,
+                        all_in,
+                    },
+                }
+            }
+            Action::AllIn => unreachable!(),
+        };
+
+        self.seats[seat].acted = true;
+        self.seats[seat].can_raise = false;
+        self.events.push(event);
+        self.to_act = Some(self.next_seat(seat, Seat::can_act));
+        self.advance()
+    }
+
+    // Finishes the hand once one player is left, or moves on to the next street once everyone
+    // who can still act has acted and matched the bet
+    fn advance(&mut self) -> Result<(), TableError> {
+        if self.seats.iter().filter(|seat| seat.is_live()).count() == 1 {
+            self.finish_hand();
+            return Ok(());
+        }
+
+        let current_bet = self.current_bet;
+        let pending = |seat: &Seat| seat.can_act() && (!seat.acted || seat.bet < current_bet);
+        let actors = self.seats.iter().filter(|seat| seat.can_act()).count();
+        let round_over = !self.seats.iter().any(pending)
+            // Nobody left to bet against
+            || (actors <= 1 && !self.seats.iter().any(|seat| seat.can_act() && seat.bet < current_bet));
+
+        if !round_over {
+            let from = self.to_act.unwrap_or(0);
+            if !pending(&self.seats[from]) {
+                self.to_act = Some(self.next_seat(from, pending));
+            }
+            return Ok(());
+        }
+
+        let (street, cards) = match self.street {
+            Some(Street::Preflop) => (Street::Flop, 3),
+            Some(Street::Flop) => (Street::Turn, 1),
+            Some(Street::Turn) => (Street::River, 1),
+            _ => {
+                self.finish_hand();
+                return Ok(());
+            }
+        };
+
+        self.deck.burn()?;
+        let cards = self.deck.deal(cards)?;
+        self.board.extend(cards.iter().cloned());
+        self.events.push(Event::Board { street, cards });
+        self.street = Some(street);
+        self.current_bet = 0;
+        self.min_raise = self.config.big_blind;
+        for seat in self.seats.iter_mut() {
+            seat.bet = 0;
+            seat.acted = false;
+            seat.can_raise = true;
+        }
+
+        let button = self.button.unwrap();
+        self.to_act = Some(self.next_seat(button, Seat::can_act));
+        self.advance()
+    }
+
+    // Splits the chips into a main pot and side pots by how much each player put in,
+    // returns uncalled chips and pays every pot to the best eligible hands
+    fn finish_hand(&mut self) {
+        let live = self.seats.iter().filter(|seat| seat.is_live()).count();
+        let ranks = self
+            .seats
+            .iter()
+            .map(|seat| match seat.is_live() && live > 1 {
+                true => Some(best_five(&[seat.hole.as_slice(), &self.board].concat()).1),
+                false => None,
+            })
+            .collect::<Vec<Option<HandRank>>>();
+        let button = self.button.unwrap();
+        for seat in self.live_seats_from(self.next_seat(button, Seat::is_live)) {
+            if let Some(rank) = &ranks[seat] {
+                self.events.push(Event::Showdown {
+                    seat,
+                    cards: self.seats[seat].hole.clone(),
+                    rank: Some(rank.clone()),
+                });
+            }
+        }
+
+        // Every distinct amount put in starts a new layer of chips, layers that the same
+        // players can win make up one pot
+        let mut levels = self
+            .seats
+            .iter()
+            .map(|seat| seat.total)
+            .filter(|&total| total > 0)
+            .collect::<Vec<u64>>();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut pots: Vec<(u64, Vec<usize>)> = vec![];
+        let mut floor = 0;
+        let mut carried = 0;
+        for level in levels {
+            let amount = carried
+                + self
+                    .seats
+                    .iter()
+                    .map(|seat| seat.total.clamp(floor, level) - floor)
+                    .sum::<u64>();
+            let eligible = (0..self.seats.len())
+                .filter(|&seat| self.seats[seat].is_live() && self.seats[seat].total >= level)
+                .collect::<Vec<usize>>();
+            let contributors = self.seats.iter().filter(|seat| seat.total >= level).count();
+            floor = level;
+            carried = 0;
+
+            match (eligible.as_slice(), pots.last_mut()) {
+                ([], _) => carried = amount,
+                (&[seat], _) if contributors == 1 => {
+                    self.seats[seat].stack += amount;
+                    self.events.push(Event::UncalledBet { seat, amount });
+                }
+                (_, Some((pot, players))) if *players == eligible => *pot += amount,
+                _ => pots.push((amount, eligible)),
+            }
+        }
+
+        let order = self.live_seats_from(self.next_seat(button, Seat::is_live));
+        for (pot, (amount, eligible)) in pots.into_iter().enumerate() {
+            let best = eligible
+                .iter()
+                .filter_map(|&seat| ranks[seat].as_ref())
+                .max();
+            let winners = order
+                .iter()
+                .filter(|seat| eligible.contains(seat) && ranks[**seat].as_ref() == best)
+                .collect::<Vec<&usize>>();
+
+            // Chips that can't be split evenly go to the first winners left of the button
+            let n = winners.len() as u64;
+            for (i, &&seat) in winners.iter().enumerate() {
+                let share = amount / n + u64::from((i as u64) < amount % n);
+                self.seats[seat].stack += share;
+                self.events.push(Event::Won {
+                    seat,
+                    pot,
+                    amount: share,
+                });
+            }
+        }
+
+        for seat in self.seats.iter_mut() {
+            seat.bet = 0;
+            seat.total = 0;
+        }
+        self.street = None;
+        self.to_act = None;
+        self.current_bet = 0;
+    }
+
+    // The next seat after `from` matching `f`, going round the table
+    fn next_seat(&self, from: usize, f: impl Fn(&Seat) -> bool) -> usize {
+        let n = self.seats.len();
+        (1..=n)
+            .map(|i| (from + i) % n)
+            .find(|&seat| f(&self.seats[seat]))
+            .unwrap_or(from)
+    }
+
+    // Seats still in the hand going round the table from `from`
+    fn live_seats_from(&self, from: usize) -> Vec<usize> {
+        let n = self.seats.len();
+        (0..n)
+            .map(|i| (from + i) % n)
+            .filter(|&seat| self.seats[seat].is_live())
+            .collect()
+    }
+
+    pub fn config(&self) -> TableConfig {
+        self.config
+    }
+
+    pub fn stacks(&self) -> Vec<u64> {
+        self.seats.iter().map(|seat| seat.stack).collect()
+    }
+
+    /// Chips put in the pot during the current hand.
+    pub fn pot(&self) -> u64 {
+        self.seats.iter().map(|seat| seat.total).sum()
+    }
+
+    pub fn hole_cards(&self, seat: usize) -> &[Card] {
+        &self.seats[seat].hole
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    pub fn button(&self) -> Option<usize> {
+        self.button
+    }
+
+    /// The street being played, `None` between hands.
+    pub fn street(&self) -> Option<Street> {
+        self.street
+    }
+
+    pub fn to_act(&self) -> Option<usize> {
+        self.to_act
+    }
+
+    /// How much the player to act has to put in to call.
+    pub fn to_call(&self) -> u64 {
+        self.to_act.map_or(0, |seat| {
+            self.current_bet.saturating_sub(self.seats[seat].bet)
+        })
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+}
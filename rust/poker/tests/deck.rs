@@ -0,0 +1,115 @@
+use poker::{Card, Deck, DeckError, DeckEvent};
+use std::collections::HashSet;
+
+fn card(card: &str) -> Card {
+    Card::try_from(card).unwrap()
+}
+
+#[test]
+fn test_new_deck_has_52_distinct_cards() {
+    let deck = Deck::new();
+    assert_eq!(deck.len(), 52);
+    assert_eq!(deck.cards().iter().collect::<HashSet<_>>().len(), 52);
+    assert_eq!(deck.cards()[0], card("2S"));
+    assert_eq!(deck.cards()[51], card("AC"));
+}
+
+#[test]
+fn test_shuffle_is_a_permutation_determined_by_the_seed() {
+    let deck = Deck::shuffled(42);
+    assert_eq!(deck, Deck::shuffled(42));
+    assert_ne!(deck.cards(), Deck::shuffled(43).cards());
+    assert_ne!(deck.cards(), Deck::new().cards());
+
+    let mut sorted = deck.cards().to_vec();
+    sorted.sort_by_key(|card| (card.card_type() as u8, card.value()));
+    assert_eq!(sorted, Deck::new().cards());
+}
+
+#[test]
+fn test_deal_and_burn_take_from_the_top() {
+    let mut deck = Deck::shuffled(7);
+    let top = deck.cards()[..4].to_vec();
+
+    assert_eq!(deck.deal(2), Ok(top[..2].to_vec()));
+    assert_eq!(deck.burn(), Ok(top[2].clone()));
+    assert_eq!(deck.deal(1), Ok(vec![top[3].clone()]));
+    assert_eq!(deck.len(), 48);
+}
+
+#[test]
+fn test_deal_more_than_left() {
+    let mut deck = Deck::new();
+    deck.deal(50).unwrap();
+    assert_eq!(
+        deck.deal(3),
+        Err(DeckError::NotEnoughCards { wanted: 3, left: 2 })
+    );
+    deck.deal(2).unwrap();
+    assert_eq!(
+        deck.burn(),
+        Err(DeckError::NotEnoughCards { wanted: 1, left: 0 })
+    );
+}
+
+#[test]
+fn test_remove_dead_cards() {
+    let mut deck = Deck::new();
+    deck.remove(&[card("AS"), card("KH")]).unwrap();
+    assert_eq!(deck.len(), 50);
+    assert!(!deck.cards().contains(&card("AS")));
+
+    assert_eq!(
+        deck.remove(&[card("2C"), card("AS")]),
+        Err(DeckError::CardNotInDeck(card("AS")))
+    );
+    assert_eq!(deck.len(), 50);
+}
+
+#[test]
+fn test_log_records_everything_in_order() {
+    let mut deck = Deck::new();
+    deck.remove(&[card("AS")]).unwrap();
+    deck.shuffle(3);
+    let hole = deck.deal(2).unwrap();
+    let burned = deck.burn().unwrap();
+
+    assert_eq!(
+        deck.log(),
+        &[
+            DeckEvent::Remove(vec![card("AS")]),
+            DeckEvent::Shuffle(3),
+            DeckEvent::Deal(hole),
+            DeckEvent::Burn(burned),
+        ]
+    );
+}
+
+#[test]
+fn test_replay_rebuilds_the_same_game() {
+    let mut deck = Deck::shuffled(99);
+    deck.deal(4).unwrap();
+    deck.burn().unwrap();
+    deck.deal(3).unwrap();
+    deck.shuffle(5);
+    deck.burn().unwrap();
+    deck.deal(1).unwrap();
+
+    assert_eq!(Deck::replay(deck.log()), Ok(deck));
+}
+
+#[test]
+fn test_replay_detects_a_tampered_log() {
+    let mut deck = Deck::shuffled(99);
+    let dealt = deck.deal(2).unwrap();
+    let mut log = deck.log().to_vec();
+    log[1] = DeckEvent::Deal(vec![dealt[1].clone(), dealt[0].clone()]);
+
+    assert_eq!(
+        Deck::replay(&log),
+        Err(DeckError::ReplayMismatch {
+            expected: vec![dealt[1].clone(), dealt[0].clone()],
+            found: dealt,
+        })
+    );
+}
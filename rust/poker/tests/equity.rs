@@ -0,0 +1,170 @@
+use poker::{equity, Card, Deck, EquityError, Mode};
+
+fn cards(cards: &str) -> Vec<Card> {
+    cards
+        .split_whitespace()
+        .map(|card| Card::try_from(card).unwrap())
+        .collect()
+}
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "{actual} is not within {tolerance} of {expected}"
+    );
+}
+
+#[test]
+fn test_exact_river_card() {
+    // Only the two remaining kings save the kings
+    let players = [cards("AS AH"), cards("KS KH")];
+    let result = equity(&players, &cards("2C 7D 9H JS"), &[], Mode::Exact).unwrap();
+
+    assert_eq!(result[0].win(), 42.0 / 44.0);
+    assert_eq!(result[1].win(), 2.0 / 44.0);
+    assert_eq!(result[1].lose(), 42.0 / 44.0);
+    assert_eq!(result[0].tie(), 0.0);
+}
+
+#[test]
+fn test_dead_cards_are_out_of_the_deck() {
+    let players = [cards("AS AH"), cards("KS KH")];
+    let dead = cards("KD 3C");
+    let result = equity(&players, &cards("2C 7D 9H JS"), &dead, Mode::Exact).unwrap();
+
+    assert_eq!(result[1].win(), 1.0 / 42.0);
+}
+
+#[test]
+fn test_complete_board_where_the_board_plays() {
+    let players = [cards("2S 3H"), cards("4C 4D"), cards("9C 9D")];
+    let result = equity(&players, &cards("10C JD QH KS AD"), &[], Mode::Exact).unwrap();
+
+    for player in result {
+        assert_eq!(player.tie(), 1.0);
+        assert_close(player.share(), 1.0 / 3.0, 1e-12);
+    }
+}
+
+#[test]
+fn test_exact_flop_probabilities_add_up() {
+    let players = [cards("AS KS"), cards("QH QD"), cards("7C 8C")];
+    let result = equity(&players, &cards("2S 9C 10S"), &[], Mode::Exact).unwrap();
+
+    for player in &result {
+        assert_close(player.win() + player.tie() + player.lose(), 1.0, 1e-12);
+    }
+    assert_close(result.iter().map(|p| p.share()).sum(), 1.0, 1e-12);
+}
+
+#[test]
+fn test_monte_carlo_is_close_to_exact_and_reproducible() {
+    let players = [cards("AS KS"), cards("QH QD")];
+    let board = cards("2S 9C 10S");
+    let mode = Mode::MonteCarlo {
+        iterations: 500,
+        seed: 42,
+    };
+
+    let exact = equity(&players, &board, &[], Mode::Exact).unwrap();
+    let sampled = equity(&players, &[], &[], mode).unwrap();
+    assert_eq!(sampled, equity(&players, &[], &[], mode).unwrap());
+
+    let flop = Mode::MonteCarlo {
+        iterations: 20_000,
+        seed: 7,
+    };
+    // 990 runouts are fewer than the iterations, so they get enumerated
+    assert_eq!(equity(&players, &board, &[], flop).unwrap(), exact);
+}
+
+#[test]
+fn test_monte_carlo_preflop() {
+    let players = [cards("AS AH"), cards("KD KC")];
+    let mode = Mode::MonteCarlo {
+        iterations: 20_000,
+        seed: 2024,
+    };
+    let result = equity(&players, &[], &[], mode).unwrap();
+
+    // Aces against kings win about 82% of the time
+    assert_close(result[0].share(), 0.82, 0.015);
+}
+
+#[test]
+fn test_equity_errors() {
+    let board = cards("2C 7D 9H JS");
+    assert_eq!(
+        equity(&[cards("AS AH")], &board, &[], Mode::Exact),
+        Err(EquityError::NotEnoughPlayers)
+    );
+    assert_eq!(
+        equity(&[cards("AS AH"), cards("9H 9D")], &board, &[], Mode::Exact),
+        Err(EquityError::DuplicateCard(Card::try_from("9H").unwrap()))
+    );
+    assert_eq!(
+        equity(
+            &[cards("AS AH"), cards("KS KH")],
+            &cards("2C 7D 9H JS 3S 4S"),
+            &[],
+            Mode::Exact
+        ),
+        Err(EquityError::TooManyBoardCards(6))
+    );
+}
+
+#[test]
+fn test_equity_rejects_cards_it_cant_deal() {
+    let board = cards("2C 7D 9H");
+    let joker = vec![Card::joker(), Card::try_from("AS").unwrap()];
+    assert_eq!(
+        equity(&[joker, cards("KS KH")], &board, &[], Mode::Exact),
+        Err(EquityError::UnexpectedJoker)
+    );
+    assert_eq!(
+        equity(
+            &[cards("AS AH"), cards("KS KH")],
+            &[board.as_slice(), &[Card::joker()]].concat(),
+            &[],
+            Mode::Exact
+        ),
+        Err(EquityError::UnexpectedJoker)
+    );
+    assert_eq!(
+        equity(&[cards("AS AH"), cards("KS")], &board, &[], Mode::Exact),
+        Err(EquityError::WrongHoleCards {
+            player: 1,
+            found: 1
+        })
+    );
+    assert_eq!(
+        equity(
+            &[cards("AS AH"), cards("KS KH")],
+            &board,
+            &[],
+            Mode::MonteCarlo {
+                iterations: 0,
+                seed: 1
+            }
+        ),
+        Err(EquityError::NoIterations)
+    );
+}
+
+#[test]
+fn test_equity_needs_cards_left_for_the_board() {
+    let deck = Deck::new().cards().to_vec();
+    let players = deck
+        .chunks(2)
+        .take(24)
+        .map(<[Card]>::to_vec)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        equity(&players, &[], &[], Mode::Exact),
+        Err(EquityError::NotEnoughCards { needed: 5, left: 4 })
+    );
+    assert_eq!(
+        equity(&players[..2], &[], &deck[4..48], Mode::Exact),
+        Err(EquityError::NotEnoughCards { needed: 5, left: 4 })
+    );
+}
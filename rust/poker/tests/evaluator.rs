@@ -0,0 +1,137 @@
+use poker::{
+    best_five, evaluate, try_winning_hands, Card, CardTypes, Category, Hand, PackedCard, Strength,
+};
+use std::collections::HashMap;
+
+fn deck() -> Vec<Card> {
+    [
+        CardTypes::Spades,
+        CardTypes::Hearts,
+        CardTypes::Diamonds,
+        CardTypes::Clovers,
+    ]
+    .iter()
+    .flat_map(|&card_type| (2..=14).map(move |value| Card::new(value, card_type).unwrap()))
+    .collect()
+}
+
+// Deals `n` distinct cards with a small linear congruential generator so failures are reproducible
+fn deal(seed: &mut u64, n: usize) -> Vec<Card> {
+    let mut deck = deck();
+    (0..n)
+        .map(|_| {
+            *seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            deck.swap_remove((*seed >> 33) as usize % deck.len())
+        })
+        .collect()
+}
+
+fn strength(hand: &str) -> Strength {
+    Hand::parse(hand).unwrap().strength()
+}
+
+#[test]
+fn test_strength_bounds() {
+    assert_eq!(strength("7S 5H 4D 3C 2S").value(), 1);
+    assert_eq!(strength("10C JC QC KC AC").value(), 7462);
+    assert_eq!(
+        strength("10C JC QC KC AC").category(),
+        Category::StraightFlush
+    );
+    assert_eq!(strength("AS AH AD KS KH").category(), Category::FullHouse);
+}
+
+#[test]
+fn test_strength_follows_rank() {
+    assert!(strength("4D AH 3S 2D 5C") < strength("2H 3C 4D 5D 6H"));
+    assert!(strength("2S 4S 5S 6S 7S") > strength("10D JH QS KD AC"));
+    assert_eq!(strength("3S 4S 5D 6H JH"), strength("3H 4H 5C 6C JD"));
+}
+
+#[test]
+fn test_winning_hands_matches_ranking_on_random_tables() {
+    let mut seed = 7;
+
+    for round in 0..5_000 {
+        let hands = (0..2 + round % 4)
+            .map(|_| {
+                let cards = deal(&mut seed, 5);
+                cards
+                    .iter()
+                    .map(Card::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<String>>();
+        let hands = hands.iter().map(String::as_str).collect::<Vec<&str>>();
+
+        let ranks = hands
+            .iter()
+            .map(|hand| Hand::parse(hand).unwrap().rank())
+            .collect::<Vec<_>>();
+        let best = ranks.iter().max().unwrap();
+        let expected = hands
+            .iter()
+            .zip(&ranks)
+            .filter(|(_, rank)| *rank == best)
+            .map(|(hand, _)| *hand)
+            .collect::<Vec<&str>>();
+
+        assert_eq!(try_winning_hands(&hands).unwrap(), expected, "{hands:?}");
+    }
+}
+
+#[test]
+fn test_six_to_eight_card_evaluation_matches_best_five() {
+    let mut seed = 11;
+
+    for round in 0..3_000 {
+        let cards = deal(&mut seed, 6 + round % 3);
+        let packed = cards.iter().map(PackedCard::from).collect::<Vec<_>>();
+        let (hand, rank) = best_five(&cards);
+
+        assert_eq!(evaluate(&packed), hand.strength());
+        assert_eq!(evaluate(&packed).category(), rank.category());
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "a card appears more than once")]
+fn test_duplicate_cards_are_caught_in_debug_builds() {
+    let ace = PackedCard::from(&Card::try_from("AS").unwrap());
+    evaluate(&[ace; 5]);
+}
+
+// Compares the tables against `HandRank` on all 2,598,960 hands,
+// run with `cargo test --release -- --include-ignored`
+#[test]
+#[ignore]
+fn test_every_five_card_hand_evaluates_like_its_rank() {
+    let deck = deck();
+    let mut seen = HashMap::new();
+    let mut hand = Vec::with_capacity(5);
+
+    for a in 0..52 {
+        for b in a + 1..52 {
+            for c in b + 1..52 {
+                for d in c + 1..52 {
+                    for e in d + 1..52 {
+                        hand.clear();
+                        hand.extend([a, b, c, d, e].map(|i| deck[i].clone()));
+                        let hand = Hand::from_cards(&hand).unwrap();
+                        let strength = *seen.entry(hand.rank()).or_insert(hand.strength());
+                        assert_eq!(hand.strength(), strength, "{hand}");
+                    }
+                }
+            }
+        }
+    }
+
+    let mut ranks = seen.into_iter().collect::<Vec<_>>();
+    ranks.sort();
+    let strengths = ranks.iter().map(|(_, s)| s.value()).collect::<Vec<u16>>();
+    assert_eq!(strengths, (1..=7462).collect::<Vec<u16>>());
+}
@@ -0,0 +1,165 @@
+use poker::{Card, CardTypes, Category, Hand, HandRank, ParseError};
+use std::collections::{HashMap, HashSet};
+
+fn rank(hand: &str) -> poker::HandRank {
+    Hand::parse(hand).unwrap().rank()
+}
+
+#[test]
+fn test_parse_sorts_cards_highest_first() {
+    let hand = Hand::parse("4S 10H AS 8D JC").unwrap();
+    assert_eq!(hand.to_string(), "AS JC 10H 8D 4S");
+}
+
+#[test]
+fn test_parse_rejects_invalid_cards() {
+    assert_eq!(
+        Hand::parse("4S 5S 7X 8D JC"),
+        Err(ParseError::BadSuit("7X".into()))
+    );
+    assert_eq!(
+        Hand::parse("4S 5S 1H 8D JC"),
+        Err(ParseError::BadRank("1H".into()))
+    );
+}
+
+#[test]
+fn test_parse_rejects_wrong_hand_size() {
+    assert_eq!(
+        Hand::parse("4S 5S 7H 8D"),
+        Err(ParseError::WrongHandSize(4))
+    );
+}
+
+#[test]
+fn test_rank_categories() {
+    let cases = [
+        ("4S 5S 7H 8D JC", Category::HighCard),
+        ("4S 4H 7H 8D JC", Category::Pair),
+        ("4S 4H 7H 7D JC", Category::TwoPairs),
+        ("4S 4H 4C 7D JC", Category::ThreeOfAKind),
+        ("4D AH 3S 2D 5C", Category::Straight),
+        ("2S 4S 5S 6S 7S", Category::Flush),
+        ("4S 5C 4C 5D 4H", Category::FullHouse),
+        ("3S 3H 2S 3D 3C", Category::FourOfAKind),
+        ("7S 8S 9S 6S 10S", Category::StraightFlush),
+    ];
+    for (hand, category) in cases {
+        assert_eq!(rank(hand).category(), category, "{hand}");
+    }
+}
+
+#[test]
+fn test_kickers_list_groups_before_singles() {
+    assert_eq!(rank("KH 7S KD 7C KS").kickers(), &[13, 7]);
+    assert_eq!(rank("2S 8H 2D 8D 3H").kickers(), &[8, 2, 3]);
+    assert_eq!(rank("4S AH AS 8C AD").kickers(), &[14, 8, 4]);
+    assert_eq!(rank("3S 5H 6S 8D 7H").kickers(), &[8, 7, 6, 5, 3]);
+}
+
+#[test]
+fn test_ace_low_straight_counts_ace_as_one() {
+    assert_eq!(rank("4D AH 3S 2D 5C").kickers(), &[5, 4, 3, 2, 1]);
+    assert!(rank("4D AH 3S 2D 5C") < rank("2H 3C 4D 5D 6H"));
+}
+
+#[test]
+fn test_ranks_compare_outside_of_winning_hands() {
+    assert!(rank("4S 5H 4C 8C 5C") > rank("2S 8H 6S 8D JH"));
+    assert!(rank("JD QH JS 8D QC") > rank("JS QS JC 2D QD"));
+    assert!(rank("5H 5S 5D 9S 9D") > rank("5H 5S 5D 8S 8D"));
+    assert_eq!(rank("3S 4S 5D 6H JH"), rank("3H 4H 5C 6C JD"));
+}
+
+#[test]
+fn test_rank_display() {
+    let cases = [
+        ("KH 7S KD 7C KS", "Full house, kings over sevens"),
+        ("4S 5S 7H 8D JC", "High card, jack"),
+        ("6S 6H 7H 8D JC", "Pair of sixes"),
+        ("4S 4H 7H 7D JC", "Two pairs, sevens and fours"),
+        ("4D AH 3S 2D 5C", "Straight, five high"),
+        ("2S 4S 5S 6S 7S", "Flush, seven high"),
+        ("3S 3H 2S 3D 3C", "Four of a kind, threes"),
+        ("10C JC QC KC AC", "Royal flush"),
+    ];
+    for (hand, name) in cases {
+        assert_eq!(rank(hand).to_string(), name);
+    }
+}
+
+#[test]
+fn test_ranks_are_totally_ordered_by_category_then_kickers() {
+    let mut ranks = [
+        "3S 3H 2S 3D 3C",
+        "4S 5H 6C 8D KH",
+        "4D AH 3S 2D 5C",
+        "KH 7S KD 7C KS",
+        "2S 4H 6S 4D JH",
+        "2H 3C 4D 5D 6H",
+    ]
+    .map(rank);
+    ranks.sort();
+
+    let categories = ranks.iter().map(HandRank::category).collect::<Vec<_>>();
+    assert_eq!(
+        categories,
+        [
+            Category::HighCard,
+            Category::Pair,
+            Category::Straight,
+            Category::Straight,
+            Category::FullHouse,
+            Category::FourOfAKind,
+        ]
+    );
+    assert_eq!(ranks[2].kickers(), &[5, 4, 3, 2, 1]);
+}
+
+// Ranks all 2,598,960 hands, run with `cargo test --release -- --include-ignored`
+#[test]
+#[ignore]
+fn test_every_five_card_hand_has_the_known_category_count() {
+    let deck = [
+        CardTypes::Spades,
+        CardTypes::Hearts,
+        CardTypes::Diamonds,
+        CardTypes::Clovers,
+    ]
+    .iter()
+    .flat_map(|&card_type| (2..=14).map(move |value| Card::new(value, card_type).unwrap()))
+    .collect::<Vec<Card>>();
+
+    let mut counts: HashMap<Category, usize> = HashMap::new();
+    let mut distinct = HashSet::new();
+    let mut hand = Vec::with_capacity(5);
+
+    for a in 0..52 {
+        for b in a + 1..52 {
+            for c in b + 1..52 {
+                for d in c + 1..52 {
+                    for e in d + 1..52 {
+                        hand.clear();
+                        hand.extend([a, b, c, d, e].map(|i| deck[i].clone()));
+                        let rank = Hand::from_cards(&hand).unwrap().rank();
+                        *counts.entry(rank.category()).or_default() += 1;
+                        distinct.insert(rank);
+                    }
+                }
+            }
+        }
+    }
+
+    assert_eq!(counts.values().sum::<usize>(), 2_598_960);
+    assert_eq!(counts[&Category::StraightFlush], 40);
+    assert_eq!(counts[&Category::FourOfAKind], 624);
+    assert_eq!(counts[&Category::FullHouse], 3_744);
+    assert_eq!(counts[&Category::Flush], 5_108);
+    assert_eq!(counts[&Category::Straight], 10_200);
+    assert_eq!(counts[&Category::ThreeOfAKind], 54_912);
+    assert_eq!(counts[&Category::TwoPairs], 123_552);
+    assert_eq!(counts[&Category::Pair], 1_098_240);
+    assert_eq!(counts[&Category::HighCard], 1_302_540);
+    // Hands only differing by suit rank the same, leaving 7462 distinct ranks
+    assert_eq!(distinct.len(), 7_462);
+}
@@ -0,0 +1,390 @@
+use poker::{
+    winning_hands, Action, Currency, Event, HandHistory, HistoryError, Table, TableConfig,
+};
+
+const CONFIG: TableConfig = TableConfig {
+    small_blind: 5,
+    big_blind: 10,
+    ante: 0,
+};
+
+const NAMES: [&str; 3] = ["alice", "bob", "carol"];
+
+const HAND: &str = "\
+PokerStars Hand #7: Hold'em No Limit (5/10) - Ante 1
+Table 'Home Game' 3-max Seat #1 is the button
+Seat 1: alice (500 in chips)
+Seat 2: bob (300 in chips)
+Seat 3: carol (1000 in chips)
+alice: posts the ante 1
+bob: posts the ante 1
+carol: posts the ante 1
+bob: posts small blind 5
+carol: posts big blind 10
+*** HOLE CARDS ***
+Dealt to alice [Ah Kh]
+Dealt to bob [Tc Td]
+Dealt to carol [7s 2d]
+alice: raises 20 to 30
+bob: calls 25
+carol: folds
+*** FLOP *** [Qh Jh 3c]
+bob: checks
+alice: bets 40
+bob: raises 229 to 269 and is all-in
+alice: calls 229
+*** TURN *** [Qh Jh 3c] [8s]
+*** RIVER *** [Qh Jh 3c 8s] [2s]
+*** SHOW DOWN ***
+alice: shows [Ah Kh] (High card, ace)
+bob: shows [Tc Td] (Pair of tens)
+bob collected 611 from pot
+*** SUMMARY ***
+Total pot 611 | Rake 0
+Board [Qh Jh 3c 8s 2s]
+";
+
+fn played_hands() -> Table {
+    let mut table = Table::new(CONFIG, &[1000, 1000, 1000]);
+    table.start_hand(3).unwrap();
+    table.act(0, Action::Call).unwrap();
+    table.act(1, Action::Call).unwrap();
+    table.act(2, Action::Check).unwrap();
+    table.act(1, Action::Bet(20)).unwrap();
+    table.act(2, Action::Fold).unwrap();
+    table.act(0, Action::Raise(60)).unwrap();
+    table.act(1, Action::Call).unwrap();
+    for _ in 0..2 {
+        table.act(1, Action::Check).unwrap();
+        table.act(0, Action::Check).unwrap();
+    }
+
+    table.start_hand(4).unwrap();
+    table.act(1, Action::Raise(30)).unwrap();
+    table.act(2, Action::Fold).unwrap();
+    table.act(0, Action::Fold).unwrap();
+    table
+}
+
+#[test]
+fn test_text_round_trip() {
+    let history = HAND.parse::<HandHistory>().unwrap();
+    assert_eq!(history.hand, 7);
+    assert_eq!(history.table, "Home Game");
+    assert_eq!(history.config.ante, 1);
+    assert_eq!(history.button, 0);
+    assert_eq!(history.players[1].stack, 300);
+    assert_eq!(history.board().len(), 5);
+    assert_eq!(history.to_string(), HAND);
+}
+
+#[test]
+fn test_table_round_trip() {
+    let table = played_hands();
+    let histories =
+        HandHistory::from_events("Replay", CONFIG, &NAMES, &[1000; 3], table.events()).unwrap();
+    assert_eq!(histories.len(), 2);
+    assert_eq!(histories[1].button, 1);
+    // Stacks at the start of the second hand follow from the first
+    let stacks = histories[1]
+        .players
+        .iter()
+        .map(|player| player.stack)
+        .collect::<Vec<u64>>();
+    assert_ne!(stacks, vec![1000; 3]);
+    assert_eq!(stacks.iter().sum::<u64>(), 3000);
+
+    let text = histories
+        .iter()
+        .map(|history| history.to_string())
+        .collect::<Vec<String>>()
+        .join("\n");
+    assert_eq!(HandHistory::parse_all(&text).unwrap(), histories);
+}
+
+#[test]
+fn test_winners_re_evaluate() {
+    let history = HAND.parse::<HandHistory>().unwrap();
+    let hands = history.showdown_hands();
+    let texts = hands
+        .iter()
+        .map(|(_, hand)| hand.to_string())
+        .collect::<Vec<String>>();
+    let winners = winning_hands(&texts.iter().map(String::as_str).collect::<Vec<&str>>());
+
+    let collected = history
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Won { seat, .. } => Some(*seat),
+            _ => None,
+        })
+        .collect::<Vec<usize>>();
+    let expected = hands
+        .iter()
+        .filter(|(_, hand)| winners.contains(&hand.to_string().as_str()))
+        .map(|(seat, _)| *seat)
+        .collect::<Vec<usize>>();
+    assert_eq!(collected, expected);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!("".parse::<HandHistory>(), Err(HistoryError::MissingHeader));
+    let unknown = HAND.replace("bob: checks", "dave: checks");
+    assert!(matches!(
+        unknown.parse::<HandHistory>(),
+        Err(HistoryError::Malformed { line: 19, .. })
+    ));
+    let unknown = HAND.replace("bob collected", "dave collected");
+    assert_eq!(
+        unknown.parse::<HandHistory>(),
+        Err(HistoryError::UnknownPlayer {
+            line: 28,
+            name: "dave".to_string()
+        })
+    );
+    let bad_card = HAND.replace("[8s]", "[1d]");
+    assert!(matches!(
+        bad_card.parse::<HandHistory>(),
+        Err(HistoryError::BadCard { line: 23, .. })
+    ));
+}
+
+#[test]
+fn test_cards_shown_before_the_river() {
+    let text = "\
+PokerStars Hand #3: Hold'em No Limit (5/10)
+Table 'Home Game' 2-max Seat #1 is the button
+Seat 1: alice (1000 in chips)
+Seat 2: bob (1000 in chips)
+alice: posts small blind 5
+bob: posts big blind 10
+*** HOLE CARDS ***
+Dealt to alice [Ah Ad]
+alice: raises 20 to 30
+bob: folds
+Uncalled bet (20) returned to alice
+alice collected 20 from pot
+alice: shows [Ah Ad]
+*** SUMMARY ***
+Total pot 20 | Rake 0
+";
+    let history = text.parse::<HandHistory>().unwrap();
+    assert!(matches!(
+        history.events.last(),
+        Some(Event::Showdown {
+            seat: 0,
+            rank: None,
+            ..
+        })
+    ));
+    assert_eq!(history.hole_cards(0).map(|cards| cards.len()), Some(2));
+    assert!(history.showdown_hands().is_empty());
+    assert_eq!(history.to_string(), text);
+}
+
+#[test]
+fn test_headers() {
+    let header = |header: &str| {
+        let text = HAND.replacen(
+            "PokerStars Hand #7: Hold'em No Limit (5/10) - Ante 1",
+            header,
+            1,
+        );
+        text.parse::<HandHistory>()
+            .map(|history| (history.hand, history.config))
+    };
+    let config = |small_blind, big_blind, ante| TableConfig {
+        small_blind,
+        big_blind,
+        ante,
+    };
+
+    assert_eq!(
+        header("PokerStars Hand #247934541234: Hold'em No Limit (5/10) - Ante 1"),
+        Ok((247934541234, config(5, 10, 1)))
+    );
+    assert_eq!(
+        header("PokerStars Hand #8: Hold'em No Limit (10/25) - Ante 25"),
+        Ok((8, config(10, 25, 25)))
+    );
+    assert_eq!(
+        header("PokerStars Hand #9: Hold'em No Limit ($1/$2 USD) - 2024/01/01 20:00:00 ET"),
+        Ok((9, config(100, 200, 0)))
+    );
+    assert_eq!(
+        header("PokerStars Hand #10: Hold'em No Limit (€5/€10 EUR) - Ante €1 - 2024/01/01"),
+        Ok((10, config(500, 1000, 100)))
+    );
+    assert!(matches!(
+        header("PokerStars Hand #99999999999999999999: Hold'em No Limit (5/10)"),
+        Err(HistoryError::Malformed { line: 1, .. })
+    ));
+    assert!(matches!(
+        header("PokerStars Hand #11: Hold'em No Limit (5/10) Ante 1"),
+        Err(HistoryError::Malformed { line: 1, .. })
+    ));
+}
+
+#[test]
+fn test_currency_amounts_are_in_cents() {
+    let text = HAND
+        .replacen("(5/10)", "($0.05/$0.10 USD)", 1)
+        .replacen("Ante 1", "Ante $0.01", 1)
+        .replace("(500 in chips)", "($5 in chips)")
+        .replace("(300 in chips)", "($3.00 in chips)")
+        .replace("(1000 in chips)", "($10.0 in chips)")
+        .replace("ante 1", "ante $0.01")
+        .replace("blind 5", "blind $0.05")
+        .replace("blind 10", "blind $0.10")
+        .replace("raises 20 to 30", "raises $0.20 to $0.30")
+        .replace("calls 25", "calls $0.25")
+        .replace("bets 40", "bets $0.40")
+        .replace("raises 229 to 269", "raises $2.29 to $2.69")
+        .replace("calls 229", "calls $2.29")
+        .replace("collected 611", "collected $6.11");
+    let history = text.parse::<HandHistory>().unwrap();
+    assert_eq!(history.config.big_blind, 10);
+    assert_eq!(
+        history.currency,
+        Some(Currency {
+            symbol: '$',
+            code: Some("USD".to_string())
+        })
+    );
+    let stacks = history.players.iter().map(|player| player.stack);
+    assert_eq!(stacks.collect::<Vec<u64>>(), [500, 300, 1000]);
+    assert_eq!(history.events, HAND.parse::<HandHistory>().unwrap().events);
+    assert_eq!(
+        history.to_string(),
+        text.replace("($5 in chips)", "($5.00 in chips)")
+            .replace("($10.0 in chips)", "($10.00 in chips)")
+            .replace("Total pot 611 | Rake 0", "Total pot $6.11 | Rake $0.00")
+    );
+
+    for amount in ["$0.123", "$-1", "$1.", "$.5", "5$"] {
+        let text = text.replace("calls $0.25", &format!("calls {amount}"));
+        assert!(text.parse::<HandHistory>().is_err(), "{amount}");
+    }
+}
+
+// A cash game hand as PokerStars writes it, with the lines which are skipped
+const CASH_GAME: &str = "\
+PokerStars Hand #247934541234:  Hold'em No Limit ($0.01/$0.02 USD) - 2024/01/14 21:03:52 CET [2024/01/14 15:03:52 ET]
+Table 'Aludra IV' 6-max Seat #4 is the button
+Seat 1: fishbowl77 ($2.13 in chips)
+Seat 2: NitNorbert ($1.94 in chips)
+Seat 3: hero_dk ($2 in chips)
+Seat 4: RiverRat_09 ($3.41 in chips)
+Seat 5: kaptajn ($2 in chips) is sitting out
+Seat 6: LuckyLuuk ($0.87 in chips)
+LuckyLuuk: posts small blind $0.01
+fishbowl77: posts big blind $0.02
+NitNorbert: posts small & big blinds $0.03
+kaptajn: is sitting out
+*** HOLE CARDS ***
+Dealt to hero_dk [Ah Qh]
+NitNorbert: checks
+hero_dk: raises $0.04 to $0.06
+RiverRat_09: calls $0.06
+LuckyLuuk: folds
+fishbowl77: folds
+NitNorbert: calls $0.04
+*** FLOP *** [Qd 7c 2h]
+NitNorbert: checks
+hero_dk: bets $0.10
+RiverRat_09: raises $0.20 to $0.30
+NitNorbert: folds
+hero_dk: calls $0.20
+fishbowl77 leaves the table
+*** TURN *** [Qd 7c 2h] [9s]
+hero_dk: checks
+RiverRat_09: bets $0.45
+hero_dk: calls $0.45
+*** RIVER *** [Qd 7c 2h 9s] [4d]
+hero_dk: checks
+RiverRat_09: checks
+*** SHOW DOWN ***
+hero_dk: shows [Ah Qh] (a pair of Queens)
+RiverRat_09: mucks hand
+hero_dk collected $1.64 from pot
+RiverRat_09 said, \"nh\"
+*** SUMMARY ***
+Total pot $1.72 | Rake $0.08
+Board [Qd 7c 2h 9s 4d]
+Seat 1: fishbowl77 (big blind) folded before Flop
+Seat 2: NitNorbert folded on the Flop
+Seat 3: hero_dk showed [Ah Qh] and won ($1.64) with a pair of Queens
+Seat 4: RiverRat_09 (button) mucked [Jd Js]
+Seat 6: LuckyLuuk (small blind) folded before Flop
+";
+
+#[test]
+fn test_cash_game() {
+    let history = CASH_GAME.parse::<HandHistory>().unwrap();
+    assert_eq!(history.hand, 247934541234);
+    assert_eq!(history.table, "Aludra IV");
+    assert_eq!(
+        history.config,
+        TableConfig {
+            small_blind: 1,
+            big_blind: 2,
+            ante: 0
+        }
+    );
+    let stacks = history
+        .players
+        .iter()
+        .map(|player| (player.seat, player.stack))
+        .collect::<Vec<(usize, u64)>>();
+    assert_eq!(stacks, [(0, 213), (1, 194), (2, 200), (3, 341), (5, 87)]);
+    assert_eq!(
+        history.events[2..4],
+        [
+            Event::SmallBlind { seat: 1, amount: 1 },
+            Event::BigBlind { seat: 1, amount: 2 }
+        ]
+    );
+    assert_eq!(history.showdown_hands().len(), 1);
+    assert_eq!(
+        history.events.last(),
+        Some(&Event::Won {
+            seat: 2,
+            pot: 0,
+            amount: 164
+        })
+    );
+
+    let text = history.to_string();
+    assert!(text.contains("NitNorbert: posts small & big blinds $0.03\n"));
+    assert!(text.contains("RiverRat_09: raises $0.20 to $0.30\n"));
+    assert_eq!(text.parse::<HandHistory>().unwrap(), history);
+}
+
+#[test]
+fn test_raises_must_go_above_the_bet() {
+    let below = HAND.replace("alice: raises 20 to 30", "alice: raises 3 to 8");
+    assert!(matches!(
+        below.parse::<HandHistory>(),
+        Err(HistoryError::Malformed { line: 15, .. })
+    ));
+    let level = HAND.replace("bob: raises 229 to 269", "bob: raises 0 to 40");
+    assert!(matches!(
+        level.parse::<HandHistory>(),
+        Err(HistoryError::Malformed { line: 21, .. })
+    ));
+
+    let mut events = played_hands().events().to_vec();
+    let raise = events
+        .iter()
+        .position(|event| matches!(event, Event::Raise { .. }))
+        .unwrap();
+    if let Event::Raise { to, .. } = &mut events[raise] {
+        *to = 5;
+    }
+    assert!(matches!(
+        HandHistory::from_events("Replay", CONFIG, &NAMES, &[1000; 3], &events),
+        Err(HistoryError::Malformed { line, .. }) if line == raise + 1
+    ));
+}
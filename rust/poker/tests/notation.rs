@@ -0,0 +1,130 @@
+use poker::{
+    AnyNotation, Card, CardTypes, CodePoints, Compact, Deck, Hand, Notation, ParseError, Standard,
+    Symbols,
+};
+
+#[test]
+fn test_compact_notation() {
+    assert_eq!(
+        Card::parse_with("Ts", &Compact),
+        Ok(Card::new(10, CardTypes::Spades).unwrap())
+    );
+    assert_eq!(
+        Card::parse_with("Ah", &Compact),
+        Ok(Card::new(14, CardTypes::Hearts).unwrap())
+    );
+    assert_eq!(
+        Card::parse_with("AH", &Compact),
+        Err(ParseError::BadSuit("AH".into()))
+    );
+    assert_eq!(
+        Card::parse_with("10s", &Compact),
+        Err(ParseError::BadRank("10s".into()))
+    );
+    let card = Card::new(10, CardTypes::Diamonds).unwrap();
+    assert_eq!(card.display_with(&Compact).to_string(), "Td");
+}
+
+#[test]
+fn test_symbol_notation() {
+    assert_eq!(
+        Card::parse_with("A♠", &Symbols),
+        Ok(Card::new(14, CardTypes::Spades).unwrap())
+    );
+    assert_eq!(
+        Card::parse_with("10♡", &Symbols),
+        Ok(Card::new(10, CardTypes::Hearts).unwrap())
+    );
+    let hand = Hand::parse("KH 10D 3C 7S AH").unwrap();
+    assert_eq!(hand.display_with(&Symbols).to_string(), "A♥ K♥ T♦ 7♠ 3♣");
+}
+
+#[test]
+fn test_code_point_notation() {
+    assert_eq!(
+        Card::parse_with("\u{1F0A1}", &CodePoints),
+        Ok(Card::new(14, CardTypes::Spades).unwrap())
+    );
+    assert_eq!(
+        Card::parse_with("\u{1F0DE}", &CodePoints),
+        Ok(Card::new(13, CardTypes::Clovers).unwrap())
+    );
+    assert_eq!(
+        Card::parse_with("\u{1F0CF}", &CodePoints),
+        Ok(Card::joker())
+    );
+    // The knight isn't part of the deck
+    assert_eq!(
+        Card::parse_with("\u{1F0AC}", &CodePoints),
+        Err(ParseError::BadRank("\u{1F0AC}".into()))
+    );
+    assert_eq!(
+        CodePoints.parse_cards("🂡🂮 🃑").unwrap(),
+        vec![
+            Card::new(14, CardTypes::Spades).unwrap(),
+            Card::new(13, CardTypes::Spades).unwrap(),
+            Card::new(14, CardTypes::Clovers).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn test_every_card_round_trips() {
+    let notations: [&dyn Notation; 5] = [&Standard, &Compact, &Symbols, &CodePoints, &AnyNotation];
+    let mut cards = Deck::new().cards().to_vec();
+    cards.push(Card::joker());
+
+    for notation in notations {
+        for card in &cards {
+            let text = card.display_with(notation).to_string();
+            assert_eq!(
+                Card::parse_with(&text, notation).as_ref(),
+                Ok(card),
+                "{text}"
+            );
+            assert_eq!(
+                Card::parse_with(&text, &AnyNotation).as_ref(),
+                Ok(card),
+                "{text}"
+            );
+        }
+        assert_eq!(
+            notation.parse_cards(&notation.format_cards(&cards)),
+            Ok(cards.clone())
+        );
+    }
+}
+
+#[test]
+fn test_any_notation_keeps_the_standard_error() {
+    assert_eq!(
+        Card::parse_with("4X", &AnyNotation),
+        Err(ParseError::BadSuit("4X".into()))
+    );
+    assert_eq!(
+        Hand::parse_with("4s 5♠ 🂧 8D JC", &AnyNotation).map(|hand| hand.to_string()),
+        Ok("JC 8D 7S 5S 4S".to_string())
+    );
+}
+
+#[test]
+fn test_any_notation_ignores_case() {
+    let ten = Card::new(10, CardTypes::Spades).unwrap();
+    for text in ["Ts", "TS", "ts", "tS", "10S"] {
+        assert_eq!(
+            Card::parse_with(text, &AnyNotation),
+            Ok(ten.clone()),
+            "{text}"
+        );
+    }
+    assert_eq!(
+        Hand::parse_with("AS KS QS JS TS", &AnyNotation).map(|hand| hand.to_string()),
+        Ok("AS KS QS JS 10S".to_string())
+    );
+}
+
+#[test]
+fn test_display_pads_like_a_string() {
+    let card = Card::new(9, CardTypes::Clovers).unwrap();
+    assert_eq!(format!("[{:>4}]", card.display_with(&Symbols)), "[  9♣]");
+}
@@ -0,0 +1,97 @@
+use poker::{try_winning_hands, Card, CardTypes, Hand, ParseError};
+
+#[test]
+fn test_card_try_from_valid_cards() {
+    assert_eq!(
+        Card::try_from("10S"),
+        Ok(Card::new(10, CardTypes::Spades).unwrap())
+    );
+    assert_eq!(
+        Card::try_from("AH"),
+        Ok(Card::new(14, CardTypes::Hearts).unwrap())
+    );
+    assert_eq!(
+        Card::try_from("2C"),
+        Ok(Card::new(2, CardTypes::Clovers).unwrap())
+    );
+}
+
+#[test]
+fn test_card_new_checks_the_value() {
+    assert_eq!(
+        Card::new(1, CardTypes::Spades),
+        Err(ParseError::BadValue(1))
+    );
+    assert_eq!(
+        Card::new(99, CardTypes::Hearts),
+        Err(ParseError::BadValue(99))
+    );
+    assert_eq!(
+        Card::new(14, CardTypes::Joker),
+        Err(ParseError::BadValue(14))
+    );
+    assert_eq!(Card::new(0, CardTypes::Joker), Ok(Card::joker()));
+    assert_eq!(
+        ParseError::BadValue(1).to_string(),
+        "value 1 is not a card, expected 2-14 or 0 for a joker"
+    );
+}
+
+#[test]
+fn test_card_try_from_bad_suit() {
+    assert_eq!(Card::try_from("4X"), Err(ParseError::BadSuit("4X".into())));
+    assert_eq!(Card::try_from("4s"), Err(ParseError::BadSuit("4s".into())));
+}
+
+#[test]
+fn test_card_try_from_bad_rank() {
+    for card in ["1S", "11H", "0D", "ZC", "S", "-2S"] {
+        assert_eq!(Card::try_from(card), Err(ParseError::BadRank(card.into())));
+    }
+}
+
+#[test]
+fn test_hand_rejects_duplicate_cards() {
+    assert_eq!(
+        Hand::parse("4S 5S 4S 8D JC"),
+        Err(ParseError::DuplicateCard("4S".into()))
+    );
+}
+
+#[test]
+fn test_try_winning_hands_rejects_malformed_hands() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JC", "2S 4H 6S 4D"]),
+        Err(ParseError::WrongHandSize(4))
+    );
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JC", "2S 4H 6S 4D 1H"]),
+        Err(ParseError::BadRank("1H".into()))
+    );
+}
+
+#[test]
+fn test_try_winning_hands_picks_winners() {
+    assert_eq!(
+        try_winning_hands(&["4S 5H 6C 8D KH", "2S 4H 6S 4D JH"]),
+        Ok(vec!["2S 4H 6S 4D JH"])
+    );
+}
+
+#[test]
+fn test_parse_error_messages_name_the_card() {
+    assert_eq!(
+        ParseError::BadSuit("7X".into()).to_string(),
+        "card `7X` has an unknown suit, expected S, H, D or C"
+    );
+    assert_eq!(
+        ParseError::DuplicateCard("4S".into()).to_string(),
+        "card `4S` appears more than once"
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid poker hand")]
+fn test_winning_hands_panics_on_malformed_hands() {
+    poker::winning_hands(&["4S 5S 7H 8D JC", "4S 5S 7H 8D ZC"]);
+}
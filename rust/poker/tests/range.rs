@@ -0,0 +1,152 @@
+use poker::{equity, range_equity, Card, Deck, EquityError, Mode, Range, RangeError};
+
+fn cards(cards: &str) -> Vec<Card> {
+    cards
+        .split_whitespace()
+        .map(|card| Card::try_from(card).unwrap())
+        .collect()
+}
+
+fn count(range: &str) -> usize {
+    Range::parse(range).unwrap().len()
+}
+
+#[test]
+fn test_combo_counts() {
+    assert_eq!(count("TT"), 6);
+    assert_eq!(count("AKs"), 4);
+    assert_eq!(count("AKo"), 12);
+    assert_eq!(count("AK"), 16);
+    assert_eq!(count("AhKh"), 1);
+    assert_eq!(count("TT+"), 30);
+    assert_eq!(count("TT-77"), 24);
+    assert_eq!(count("ATs+"), 16);
+    assert_eq!(count("A9s-A2s"), 32);
+    assert_eq!(count("98s-65s"), 16);
+    assert_eq!(count("AKs, TT+, 98s-65s"), 50);
+    // Overlapping items count a combo once
+    assert_eq!(count("AK, AKs, AhKh"), 16);
+    assert_eq!(count(""), 0);
+}
+
+#[test]
+fn test_weights() {
+    let range = Range::parse("AKs:0.5, AhKh, QQ:0").unwrap();
+    assert_eq!(range.len(), 4);
+    assert_eq!(range.weighted_len(), 2.5);
+    assert_eq!(Range::parse("QQ, QQ:0").unwrap().len(), 0);
+    assert_eq!(Range::parse("AhKh:0.25").unwrap().to_string(), "AhKh:0.25");
+}
+
+#[test]
+fn test_card_removal() {
+    let range = Range::parse("AA, AKs").unwrap();
+    let left = range.without(&cards("AS 7D"));
+    assert_eq!(left.len(), 3 + 3);
+    assert!(left
+        .combos()
+        .iter()
+        .all(|combo| !combo.blocks(&cards("AS"))));
+}
+
+#[test]
+fn test_bad_ranges() {
+    for item in [
+        "AKx", "KAs", "AAs", "T9s-A2s", "TT-98s", "ZZ", "AhAh", "AK+-",
+    ] {
+        assert_eq!(Range::parse(item), Err(RangeError::BadItem(item.into())));
+    }
+    assert_eq!(
+        Range::parse("AK:2"),
+        Err(RangeError::BadWeight("AK:2".into()))
+    );
+}
+
+#[test]
+fn test_single_combos_match_equity() {
+    let board = cards("2C 7D 9H JS");
+    let ranges = [Range::parse("AsAh").unwrap(), Range::parse("KsKh").unwrap()];
+    let players = [cards("AS AH"), cards("KS KH")];
+
+    assert_eq!(
+        range_equity(&ranges, &board, &[], Mode::Exact).unwrap(),
+        equity(&players, &board, &[], Mode::Exact).unwrap()
+    );
+}
+
+#[test]
+fn test_exact_ranges_on_the_river() {
+    // Kings beat every combo of queens and lose to every ace
+    let board = cards("2C 7D 9H JS 3S");
+    let ranges = [Range::parse("KK").unwrap(), Range::parse("AA, QQ").unwrap()];
+    let result = range_equity(&ranges, &board, &[], Mode::Exact).unwrap();
+
+    // Each of the 6 kings meets 6 aces and 6 queens
+    assert!((result[0].win() - 0.5).abs() < 1e-9);
+    assert!((result[1].lose() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_sampled_ranges() {
+    let ranges = [Range::parse("AA").unwrap(), Range::parse("KK").unwrap()];
+    let mode = Mode::MonteCarlo {
+        iterations: 20_000,
+        seed: 9,
+    };
+    let result = range_equity(&ranges, &[], &[], mode).unwrap();
+
+    assert!((result[0].share() - 0.82).abs() < 0.015, "{:?}", result[0]);
+    assert_eq!(result, range_equity(&ranges, &[], &[], mode).unwrap());
+}
+
+#[test]
+fn test_range_errors() {
+    let aces = Range::parse("AsAh").unwrap();
+    assert_eq!(
+        range_equity(&[aces.clone(), aces.clone()], &[], &[], Mode::Exact),
+        Err(EquityError::ConflictingRanges)
+    );
+    assert_eq!(
+        range_equity(
+            &[Range::parse("KK").unwrap(), aces],
+            &cards("AS"),
+            &[],
+            Mode::Exact
+        ),
+        Err(EquityError::EmptyRange(1))
+    );
+}
+
+#[test]
+fn test_range_equity_needs_cards_for_every_range() {
+    let pairs = Range::parse("22+").unwrap();
+    assert_eq!(
+        range_equity(&vec![pairs.clone(); 24], &[], &[], Mode::Exact),
+        Err(EquityError::NotEnoughCards {
+            needed: 53,
+            left: 52
+        })
+    );
+    let deck = Deck::new();
+    let dead = &deck.cards()[..45];
+    assert_eq!(
+        range_equity(&[pairs.clone(), pairs], &[], dead, Mode::Exact),
+        Err(EquityError::NotEnoughCards { needed: 9, left: 7 })
+    );
+}
+
+#[test]
+fn test_range_equity_needs_iterations() {
+    assert_eq!(
+        range_equity(
+            &[Range::parse("AA").unwrap(), Range::parse("KK").unwrap()],
+            &[],
+            &[],
+            Mode::MonteCarlo {
+                iterations: 0,
+                seed: 1
+            }
+        ),
+        Err(EquityError::NoIterations)
+    );
+}
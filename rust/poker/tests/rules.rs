@@ -0,0 +1,202 @@
+use poker::{Card, Category, ParseError, Ranking, RuleSet};
+
+fn cards(cards: &str) -> Vec<Card> {
+    cards
+        .split_whitespace()
+        .map(|card| Card::try_from(card).unwrap())
+        .collect()
+}
+
+fn rank(rules: RuleSet, hand: &str) -> poker::HandRank {
+    rules.rank(&cards(hand)).unwrap()
+}
+
+#[test]
+fn test_high_rules_match_winning_hands() {
+    let hands = ["4S 5H 4C 8D 4H", "4D AH 3S 2D 5C", "2S 4S 5S 6S 7S"];
+    assert_eq!(
+        RuleSet::default().winning_hands(&hands),
+        Ok(poker::winning_hands(&hands))
+    );
+}
+
+#[test]
+fn test_ace_to_five_wheel_is_the_best_low() {
+    let rules = RuleSet::new(Ranking::AceToFive);
+    let wheel = rank(rules, "AS 2H 3D 4C 5S");
+
+    assert_eq!(wheel.category(), Category::HighCard);
+    assert_eq!(wheel.kickers(), &[5, 4, 3, 2, 1]);
+    // Straights and flushes don't count against a low
+    assert_eq!(
+        rules.winning_hands(&["AS 2S 3S 4S 5S", "AH 2D 3C 4S 6H", "KS QS JS 9S 8D"]),
+        Ok(vec!["AS 2S 3S 4S 5S"])
+    );
+}
+
+#[test]
+fn test_ace_to_five_pairs_lose_to_any_high_card() {
+    let rules = RuleSet::new(Ranking::AceToFive);
+    assert_eq!(
+        rules.winning_hands(&["AS AH 2D 3C 4S", "KS QH JD 10C 9S"]),
+        Ok(vec!["KS QH JD 10C 9S"])
+    );
+}
+
+#[test]
+fn test_deuce_to_seven() {
+    let rules = RuleSet::new(Ranking::DeuceToSeven);
+
+    assert_eq!(
+        rules.winning_hands(&["7S 5H 4D 3C 2S", "7H 6H 4S 3D 2D", "AS 2H 3D 4C 5S"]),
+        Ok(vec!["7S 5H 4D 3C 2S"])
+    );
+    // A-2-3-4-5 is only ace high and straights and flushes are bad
+    assert_eq!(rank(rules, "AS 2H 3D 4C 5S").category(), Category::HighCard);
+    assert_eq!(
+        rules.winning_hands(&["6S 5H 4D 3C 2S", "8S 6H 4D 3C 2S", "7H 5H 4H 3H 2H"]),
+        Ok(vec!["8S 6H 4D 3C 2S"])
+    );
+}
+
+#[test]
+fn test_short_deck() {
+    let rules = RuleSet::new(Ranking::ShortDeck);
+
+    let low_straight = rank(rules, "AS 6H 7D 8C 9S");
+    assert_eq!(low_straight.category(), Category::Straight);
+    assert_eq!(low_straight.kickers(), &[9, 8, 7, 6, 5]);
+    assert_eq!(
+        rules.winning_hands(&["KS KH KD 9C 9S", "AH 6H 8H 10H QH"]),
+        Ok(vec!["AH 6H 8H 10H QH"])
+    );
+    assert_eq!(
+        rules.winning_hands(&["AS 6H 7D 8C 9S", "6S 7H 8D 9C 10S"]),
+        Ok(vec!["6S 7H 8D 9C 10S"])
+    );
+    assert_eq!(
+        rank(rules, "AH 6H 7H 8H 9H").category(),
+        Category::StraightFlush
+    );
+    assert_eq!(
+        rules.rank(&cards("AS 2H 3D 4C 5S")),
+        Err(ParseError::NotInDeck("2H".to_string()))
+    );
+}
+
+#[test]
+fn test_deuces_wild() {
+    let rules = RuleSet::default().with_deuces_wild();
+
+    let five = rank(rules, "AS AH AD AC 2S");
+    assert_eq!(five.category(), Category::FiveOfAKind);
+    assert_eq!(five.to_string(), "Five of a kind, aces");
+
+    let flush = rank(rules, "2C 9H 4H 6H KH");
+    assert_eq!(flush.category(), Category::Flush);
+    assert_eq!(flush.kickers()[0], 14);
+
+    assert_eq!(
+        rank(rules, "2C 2D 10H JH KH").category(),
+        Category::StraightFlush
+    );
+}
+
+#[test]
+fn test_jokers_wild() {
+    let rules = RuleSet::default().with_jokers_wild();
+
+    let straight = rank(rules, "JK 9S 10H QD KC");
+    assert_eq!(straight.category(), Category::Straight);
+    assert_eq!(straight.kickers(), &[13, 12, 11, 10, 9]);
+    assert_eq!(
+        rank(rules, "JK JK 7S 7H 7D").category(),
+        Category::FiveOfAKind
+    );
+}
+
+#[test]
+fn test_wild_card_in_a_low_game_is_the_best_missing_card() {
+    let rules = RuleSet::new(Ranking::AceToFive).with_jokers_wild();
+    assert_eq!(rank(rules, "JK 2S 3H 4D 5C").kickers(), &[5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn test_wild_card_only_makes_a_flush_when_it_helps() {
+    let rules = RuleSet::new(Ranking::DeuceToSeven).with_jokers_wild();
+    let low = rank(rules, "7S 5S 4S 3S JK");
+    assert_eq!(low.category(), Category::HighCard);
+    assert_eq!(low.kickers(), &[7, 5, 4, 3, 2]);
+    assert_eq!(
+        rules.winning_hands(&["7S 5S 4S 3S JK", "7H 6S 4D 3C 2S"]),
+        Ok(vec!["7S 5S 4S 3S JK"])
+    );
+
+    let rules = RuleSet::default().with_jokers_wild();
+    assert_eq!(
+        rank(rules, "6S 5S 4S 3S JK").category(),
+        Category::StraightFlush
+    );
+}
+
+#[test]
+fn test_jokers_must_be_wild() {
+    assert_eq!(
+        RuleSet::default().rank(&cards("JK 9S 10H QD KC")),
+        Err(ParseError::UnexpectedJoker)
+    );
+    assert_eq!(
+        poker::Hand::parse("JK 9S 10H QD KC"),
+        Err(ParseError::UnexpectedJoker)
+    );
+    assert_eq!(
+        RuleSet::default()
+            .with_jokers_wild()
+            .rank(&cards("JK JK JK QD KC")),
+        Err(ParseError::TooManyJokers(3))
+    );
+}
+
+#[test]
+fn test_razz_best_low_of_seven() {
+    let rules = RuleSet::new(Ranking::AceToFive);
+    let (hand, rank) = rules.best_five(&cards("KS 8H 8D 3C AS 5H 2D")).unwrap();
+
+    assert_eq!(rank.kickers(), &[8, 5, 3, 2, 1]);
+    assert_eq!(hand.len(), 5);
+}
+
+#[test]
+fn test_all_wild_hands() {
+    let hand = "2S 2H 2D 2C JK";
+    let rules = |ranking| RuleSet::new(ranking).with_deuces_wild().with_jokers_wild();
+
+    let high = rank(rules(Ranking::High), hand);
+    assert_eq!(high.category(), Category::FiveOfAKind);
+    assert_eq!(high.kickers(), &[14]);
+    let ace_to_five = rank(rules(Ranking::AceToFive), hand);
+    assert_eq!(ace_to_five.category(), Category::HighCard);
+    assert_eq!(ace_to_five.kickers(), &[5, 4, 3, 2, 1]);
+    let deuce_to_seven = rank(rules(Ranking::DeuceToSeven), hand);
+    assert_eq!(deuce_to_seven.category(), Category::HighCard);
+    assert_eq!(deuce_to_seven.kickers(), &[7, 5, 4, 3, 2]);
+    assert_eq!(
+        rules(Ranking::DeuceToSeven).winning_hands(&[hand, "8S 6H 4D 3C 7S"]),
+        Ok(vec![hand])
+    );
+    // Short deck has no deuces
+    assert_eq!(
+        rules(Ranking::ShortDeck).rank(&cards(hand)),
+        Err(ParseError::NotInDeck("2S".to_string()))
+    );
+    let short_deck = rank(
+        RuleSet::new(Ranking::ShortDeck).with_jokers_wild(),
+        "JK JK 6S 6H 6D",
+    );
+    assert_eq!(short_deck.category(), Category::FiveOfAKind);
+
+    // Wild cards in a flush take values it doesn't have
+    let flush = rank(RuleSet::default().with_jokers_wild(), "AS KS 9S 4S JK");
+    assert_eq!(flush.category(), Category::Flush);
+    assert_eq!(flush.kickers(), &[14, 13, 12, 9, 4]);
+}
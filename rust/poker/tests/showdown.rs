@@ -0,0 +1,89 @@
+use poker::{best_five, showdown, Card, Category, Game};
+
+fn cards(cards: &str) -> Vec<Card> {
+    cards
+        .split_whitespace()
+        .map(|card| Card::try_from(card).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_best_five_of_five_is_the_hand_itself() {
+    let (hand, rank) = best_five(&cards("4S 5H 4C 8C 5C"));
+    assert_eq!(hand.to_string(), "8C 5H 5C 4S 4C");
+    assert_eq!(rank.category(), Category::TwoPairs);
+}
+
+#[test]
+fn test_best_five_of_seven() {
+    let (hand, rank) = best_five(&cards("AS KD 2S 7S 9S JS 3H"));
+    assert_eq!(hand.to_string(), "AS JS 9S 7S 2S");
+    assert_eq!(rank.category(), Category::Flush);
+}
+
+#[test]
+fn test_best_five_finds_the_straight_hidden_among_pairs() {
+    let (_, rank) = best_five(&cards("5D 6C 7H 8S 9D 9C 5S"));
+    assert_eq!(rank.category(), Category::Straight);
+    assert_eq!(rank.kickers(), &[9, 8, 7, 6, 5]);
+}
+
+#[test]
+#[should_panic]
+fn test_best_five_needs_five_cards() {
+    best_five(&cards("AS KD 2S 7S"));
+}
+
+#[test]
+fn test_omaha_must_use_exactly_two_hole_cards() {
+    // Four spades on the board with one in hand is not a flush in Omaha
+    let board = cards("2S 5S 9S JS KD");
+    let hole = cards("AS AH 7C 8D");
+
+    assert_eq!(
+        Game::Holdem.best_hand(&hole, &board).1.category(),
+        Category::Flush
+    );
+    let (hand, rank) = Game::Omaha.best_hand(&hole, &board);
+    assert_eq!(rank.category(), Category::Pair);
+    assert_eq!(hand.to_string(), "AS AH KD JS 9S");
+}
+
+#[test]
+fn test_omaha_must_use_exactly_three_board_cards() {
+    // Trips in hand only play as a pair with three board cards
+    let board = cards("2C 5D 9H JS KD");
+    let hole = cards("QS QH QC 3D");
+
+    let (_, rank) = Game::Omaha.best_hand(&hole, &board);
+    assert_eq!(rank.category(), Category::Pair);
+    assert_eq!(rank.kickers(), &[12, 13, 11, 9]);
+}
+
+#[test]
+fn test_showdown_single_winner() {
+    let board = cards("2C 5D 9H JS KD");
+    let result = showdown(&board, &[cards("AS AH"), cards("KS QH"), cards("3C 4C")]);
+
+    assert_eq!(result.winners(), &[0]);
+    assert_eq!(result.hands()[1].1.category(), Category::Pair);
+    assert_eq!(result.split_pot(100), vec![(0, 100)]);
+}
+
+#[test]
+fn test_showdown_board_plays_for_a_split_pot() {
+    let board = cards("10C JD QH KS AD");
+    let result = showdown(&board, &[cards("2S 3H"), cards("4C 4D"), cards("9C 9D")]);
+
+    assert_eq!(result.winners(), &[0, 1, 2]);
+    assert_eq!(result.split_pot(100), vec![(0, 34), (1, 33), (2, 33)]);
+}
+
+#[test]
+fn test_omaha_showdown() {
+    let board = cards("2S 5S 9S JS KD");
+    let result = Game::Omaha.showdown(&board, &[cards("AS AH 7C 8D"), cards("3S 4S 6H 6D")]);
+
+    assert_eq!(result.winners(), &[1]);
+    assert_eq!(result.hands()[1].1.category(), Category::Flush);
+}
@@ -0,0 +1,225 @@
+use poker::{Action, Event, Street, Table, TableConfig, TableError};
+
+const CONFIG: TableConfig = TableConfig {
+    small_blind: 5,
+    big_blind: 10,
+    ante: 0,
+};
+
+fn won(table: &Table) -> Vec<(usize, usize, u64)> {
+    table
+        .events()
+        .iter()
+        .filter_map(|event| match *event {
+            Event::Won { seat, pot, amount } => Some((seat, pot, amount)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_blinds_and_first_to_act() {
+    let mut table = Table::new(CONFIG, &[1000, 1000, 1000]);
+    table.start_hand(1).unwrap();
+
+    assert_eq!(table.button(), Some(0));
+    assert_eq!(table.stacks(), vec![1000, 995, 990]);
+    assert_eq!(table.to_act(), Some(0));
+    assert_eq!(table.to_call(), 10);
+    assert_eq!(table.street(), Some(Street::Preflop));
+    assert!((0..3).all(|seat| table.hole_cards(seat).len() == 2));
+}
+
+#[test]
+fn test_invalid_actions() {
+    let mut table = Table::new(CONFIG, &[1000, 1000, 1000]);
+    assert_eq!(
+        table.act(0, Action::Call),
+        Err(TableError::NoHandInProgress)
+    );
+    table.start_hand(1).unwrap();
+
+    assert_eq!(
+        table.act(1, Action::Call),
+        Err(TableError::NotYourTurn { seat: 1, to_act: 0 })
+    );
+    assert_eq!(
+        table.act(0, Action::Check),
+        Err(TableError::CannotCheck { to_call: 10 })
+    );
+    assert_eq!(table.act(0, Action::Bet(50)), Err(TableError::CannotBet));
+    assert_eq!(
+        table.act(0, Action::Raise(15)),
+        Err(TableError::BetTooSmall { min: 20 })
+    );
+    assert_eq!(
+        table.act(0, Action::Raise(2000)),
+        Err(TableError::NotEnoughChips { stack: 1000 })
+    );
+    assert_eq!(table.start_hand(2), Err(TableError::HandInProgress));
+}
+
+#[test]
+fn test_everyone_folds_to_the_big_blind() {
+    let mut table = Table::new(CONFIG, &[1000, 1000, 1000]);
+    table.start_hand(1).unwrap();
+    table.act(0, Action::Fold).unwrap();
+    table.act(1, Action::Fold).unwrap();
+
+    assert_eq!(table.street(), None);
+    assert_eq!(table.stacks(), vec![1000, 995, 1005]);
+    assert_eq!(won(&table), vec![(2, 0, 10)]);
+    assert!(table
+        .events()
+        .contains(&Event::UncalledBet { seat: 2, amount: 5 }));
+}
+
+#[test]
+fn test_streets_and_showdown() {
+    let mut table = Table::new(CONFIG, &[1000, 1000, 1000]);
+    table.start_hand(3).unwrap();
+    table.act(0, Action::Call).unwrap();
+    table.act(1, Action::Call).unwrap();
+    // The big blind has the option
+    assert_eq!(table.to_act(), Some(2));
+    table.act(2, Action::Check).unwrap();
+
+    assert_eq!(table.street(), Some(Street::Flop));
+    assert_eq!(table.board().len(), 3);
+    assert_eq!(table.to_act(), Some(1));
+    table.act(1, Action::Bet(20)).unwrap();
+    table.act(2, Action::Fold).unwrap();
+    table.act(0, Action::Raise(60)).unwrap();
+    table.act(1, Action::Call).unwrap();
+
+    for street in [Street::Turn, Street::River] {
+        assert_eq!(table.street(), Some(street));
+        table.act(1, Action::Check).unwrap();
+        table.act(0, Action::Check).unwrap();
+    }
+
+    assert_eq!(table.street(), None);
+    assert_eq!(table.board().len(), 5);
+    assert_eq!(table.stacks().iter().sum::<u64>(), 3000);
+    let showdowns = table
+        .events()
+        .iter()
+        .filter(|event| matches!(event, Event::Showdown { .. }))
+        .count();
+    assert_eq!(showdowns, 2);
+    assert_eq!(won(&table).iter().map(|w| w.2).sum::<u64>(), 150);
+}
+
+#[test]
+fn test_side_pots_for_all_in_players() {
+    let mut table = Table::new(CONFIG, &[100, 300, 1000]);
+    table.start_hand(5).unwrap();
+    table.act(0, Action::AllIn).unwrap();
+    table.act(1, Action::AllIn).unwrap();
+    table.act(2, Action::Call).unwrap();
+
+    // Nobody can bet anymore, so the board runs out
+    assert_eq!(table.street(), None);
+    assert_eq!(table.board().len(), 5);
+    assert_eq!(table.stacks().iter().sum::<u64>(), 1400);
+
+    let pot = |n| {
+        won(&table)
+            .iter()
+            .filter(|w| w.1 == n)
+            .map(|w| w.2)
+            .sum::<u64>()
+    };
+    assert_eq!(pot(0), 300);
+    assert_eq!(pot(1), 400);
+    assert!(won(&table).iter().all(|w| w.1 == 0 || w.0 != 0));
+}
+
+#[test]
+fn test_short_all_in_does_not_reopen_the_raising() {
+    let mut table = Table::new(CONFIG, &[1000, 1000, 150]);
+    table.start_hand(1).unwrap();
+    table.act(0, Action::Raise(100)).unwrap();
+    table.act(1, Action::Call).unwrap();
+    table.act(2, Action::AllIn).unwrap();
+
+    assert!(table.events().contains(&Event::Raise {
+        seat: 2,
+        to: 150,
+        all_in: true
+    }));
+    assert_eq!(
+        table.act(0, Action::Raise(400)),
+        Err(TableError::CannotRaise)
+    );
+    table.act(0, Action::Call).unwrap();
+    assert_eq!(
+        table.act(1, Action::Raise(400)),
+        Err(TableError::CannotRaise)
+    );
+    table.act(1, Action::Call).unwrap();
+    assert_eq!(table.street(), Some(Street::Flop));
+}
+
+#[test]
+fn test_heads_up_button_posts_small_blind() {
+    let mut table = Table::new(CONFIG, &[500, 500]);
+    table.start_hand(9).unwrap();
+
+    assert_eq!(table.stacks(), vec![495, 490]);
+    assert_eq!(table.to_act(), Some(0));
+    table.act(0, Action::Call).unwrap();
+    table.act(1, Action::Check).unwrap();
+    assert_eq!(table.to_act(), Some(1));
+}
+
+#[test]
+fn test_antes_and_moving_button() {
+    let config = TableConfig { ante: 2, ..CONFIG };
+    let mut table = Table::new(config, &[1000, 1000, 1000]);
+    table.start_hand(1).unwrap();
+    assert_eq!(table.stacks(), vec![998, 993, 988]);
+    assert_eq!(table.pot(), 21);
+
+    table.act(0, Action::Fold).unwrap();
+    table.act(1, Action::Fold).unwrap();
+    table.start_hand(2).unwrap();
+    assert_eq!(table.button(), Some(1));
+    assert_eq!(table.to_act(), Some(1));
+}
+
+#[test]
+fn test_busted_players_sit_out() {
+    let mut table = Table::new(CONFIG, &[0, 0, 100]);
+    assert_eq!(table.start_hand(1), Err(TableError::NotEnoughPlayers));
+}
+
+#[test]
+fn test_replay_rebuilds_the_log() {
+    let stacks = [200, 1000, 1000, 50];
+    let mut table = Table::new(CONFIG, &stacks);
+    table.start_hand(11).unwrap();
+    table.act(3, Action::AllIn).unwrap();
+    table.act(0, Action::Raise(100)).unwrap();
+    table.act(1, Action::Fold).unwrap();
+    table.act(2, Action::Call).unwrap();
+    table.act(2, Action::Check).unwrap();
+    table.act(0, Action::AllIn).unwrap();
+    table.act(2, Action::Call).unwrap();
+    table.start_hand(12).unwrap();
+
+    let replayed = Table::replay(CONFIG, &stacks, table.events()).unwrap();
+    assert_eq!(replayed.events(), table.events());
+    assert_eq!(replayed.stacks(), table.stacks());
+
+    let mut tampered = table.events().to_vec();
+    tampered[0] = Event::HandStarted {
+        hand: 1,
+        button: 0,
+        seed: 99,
+    };
+    assert!(matches!(
+        Table::replay(CONFIG, &stacks, &tampered),
+        Err(TableError::ReplayMismatch { .. })
+    ));
+}